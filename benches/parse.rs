@@ -0,0 +1,49 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde_json::json;
+use wordsapi::{try_parse, try_parse_borrowed, Word, WordRef};
+
+fn large_payload(entries: usize) -> String {
+    let words =
+        |prefix: &str| -> Vec<String> { (0..12).map(|i| format!("{}-{}", prefix, i)).collect() };
+    let results: Vec<_> = (0..entries)
+        .map(|i| {
+            json!({
+                "definition": format!("definition number {} of a rather long word", i),
+                "partOfSpeech": "noun",
+                "synonyms": words("synonym"),
+                "typeOf": words("hypernym"),
+                "hasTypes": words("hyponym"),
+                "partOf": words("holonym"),
+                "hasParts": words("meronym"),
+                "examples": words("an example sentence using the word"),
+            })
+        })
+        .collect();
+    json!({
+        "word": "example",
+        "frequency": 4.5,
+        "pronunciation": {"all": "ɪɡ'zæmpəl"},
+        "results": results,
+    })
+    .to_string()
+}
+
+fn parse(c: &mut Criterion) {
+    let json = large_payload(2_000);
+    c.bench_function("try_parse Word", |b| {
+        b.iter(|| try_parse::<Word>(black_box(&json)).unwrap())
+    });
+    c.bench_function("try_parse_borrowed WordRef", |b| {
+        b.iter(|| try_parse_borrowed::<WordRef>(black_box(&json)).unwrap())
+    });
+    c.bench_function("try_parse_borrowed WordRef to_owned", |b| {
+        b.iter(|| {
+            try_parse_borrowed::<WordRef>(black_box(&json))
+                .unwrap()
+                .to_owned()
+        })
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
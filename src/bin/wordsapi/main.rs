@@ -0,0 +1,179 @@
+#![forbid(unsafe_code)]
+mod output;
+mod repl;
+
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use serde_json::Value;
+use std::path::PathBuf;
+use std::process;
+use wordsapi::{Cache, Client, Config, ConfigError, RequestError, RequestType, SearchQuery, Word};
+
+fn main() {
+    let matches = cli().get_matches();
+    let json = matches.get_flag("json");
+    let profile = matches.get_one::<String>("profile").map(String::as_str);
+    let client = match client(profile) {
+        Ok(client) => client,
+        Err(ConfigError::MissingToken(profile)) => {
+            eprintln!(
+                "wordsapi: set WORDSAPI_TOKEN or give the {} profile a token in {}",
+                profile,
+                Config::path().display()
+            );
+            process::exit(2);
+        }
+        Err(error) => {
+            eprintln!("wordsapi: {}", error);
+            process::exit(2);
+        }
+    };
+    if let Some(("repl", _)) = matches.subcommand() {
+        let client = match client.cache() {
+            Some(_) => client,
+            None => client.with_cache(Cache::new()),
+        };
+        if let Err(error) = repl::run(&client, &history_path()) {
+            eprintln!("wordsapi: {}", error);
+            process::exit(1);
+        }
+        return;
+    }
+    let result = match matches.subcommand() {
+        Some(("search", args)) => search(&client, args, json),
+        Some(("random", _)) => client
+            .random()
+            .and_then(|response| output::word(&response, json)),
+        Some((name, args)) => look_up(&client, name, args, json),
+        None => unreachable!("a subcommand is required"),
+    };
+    if let Err(error) = result {
+        match error {
+            RequestError::NotFound { word } => eprintln!("wordsapi: no such word: {}", word),
+            error => eprintln!("wordsapi: {}", error),
+        }
+        process::exit(1);
+    }
+}
+
+fn cli() -> Command {
+    let mut command = Command::new("wordsapi")
+        .about("Look up words with the Words API")
+        .version(env!("CARGO_PKG_VERSION"))
+        .subcommand_required(true)
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Print the API's JSON response"),
+        )
+        .arg(
+            Arg::new("profile")
+                .long("profile")
+                .global(true)
+                .help("The configuration profile to use"),
+        );
+    for request_type in RequestType::ALL.iter() {
+        let about = match *request_type {
+            RequestType::Word => "Show everything about a word".to_owned(),
+            _ => format!("Show {} for a word", request_type.name()),
+        };
+        command = command.subcommand(
+            Command::new(kebab_case(request_type.name()))
+                .about(about)
+                .arg(
+                    Arg::new("word")
+                        .required(true)
+                        .num_args(1..)
+                        .help("The word or phrase to look up"),
+                ),
+        );
+    }
+    command
+        .subcommand(
+            Command::new("search")
+                .about("Search for words matching all of the given criteria")
+                .arg(text_arg(
+                    "pattern",
+                    "A regular expression the word must match",
+                ))
+                .arg(count_arg("letters", "Number of letters"))
+                .arg(count_arg("letters-min", "Minimum number of letters"))
+                .arg(count_arg("letters-max", "Maximum number of letters"))
+                .arg(text_arg("part-of-speech", "Part of speech, e.g. noun"))
+                .arg(zipf_arg("frequency-min", "Minimum Zipf frequency"))
+                .arg(zipf_arg("frequency-max", "Maximum Zipf frequency"))
+                .arg(text_arg(
+                    "has-details",
+                    "A detail the word must have, e.g. definitions",
+                ))
+                .arg(count_arg("limit", "Results per page"))
+                .arg(count_arg("page", "Page of results to show")),
+        )
+        .subcommand(Command::new("random").about("Show a random word"))
+        .subcommand(Command::new("repl").about("Explore words interactively"))
+}
+
+fn text_arg(name: &'static str, help: &'static str) -> Arg {
+    Arg::new(name).long(name).help(help)
+}
+
+fn count_arg(name: &'static str, help: &'static str) -> Arg {
+    text_arg(name, help).value_parser(value_parser!(usize))
+}
+
+fn zipf_arg(name: &'static str, help: &'static str) -> Arg {
+    text_arg(name, help).value_parser(value_parser!(f32))
+}
+
+fn kebab_case(name: &str) -> String {
+    let mut kebab = String::with_capacity(name.len() + 4);
+    for c in name.chars() {
+        if c.is_uppercase() {
+            kebab.push('-');
+        }
+        kebab.extend(c.to_lowercase());
+    }
+    kebab
+}
+
+fn look_up(client: &Client, name: &str, args: &ArgMatches, json: bool) -> Result<(), RequestError> {
+    let request_type = name
+        .parse::<RequestType>()
+        .expect("subcommands are named after request types");
+    let word = args
+        .get_many::<String>("word")
+        .expect("word is required")
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(" ");
+    match request_type {
+        RequestType::Word => output::word(&client.look_up::<Word>(&word)?, json),
+        _ => output::value(&client.look_up_as::<Value>(&word, &request_type)?, json),
+    }
+}
+
+fn search(client: &Client, args: &ArgMatches, json: bool) -> Result<(), RequestError> {
+    let query = SearchQuery {
+        letter_pattern: args.get_one::<String>("pattern").cloned(),
+        letters: args.get_one::<usize>("letters").cloned(),
+        letters_min: args.get_one::<usize>("letters-min").cloned(),
+        letters_max: args.get_one::<usize>("letters-max").cloned(),
+        part_of_speech: args.get_one::<String>("part-of-speech").cloned(),
+        frequency_min: args.get_one::<f32>("frequency-min").cloned(),
+        frequency_max: args.get_one::<f32>("frequency-max").cloned(),
+        has_details: args.get_one::<String>("has-details").cloned(),
+        limit: args.get_one::<usize>("limit").cloned(),
+        page: args.get_one::<usize>("page").cloned(),
+        ..SearchQuery::default()
+    };
+    output::search(&client.search(&query)?, json)
+}
+
+fn client(profile: Option<&str>) -> Result<Client, ConfigError> {
+    Config::load()?.profile(profile)?.client()
+}
+
+fn history_path() -> PathBuf {
+    Config::path().with_file_name("history")
+}
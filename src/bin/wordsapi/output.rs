@@ -0,0 +1,153 @@
+use serde_json::Value;
+use wordsapi::{Entry, RequestError, Response, SearchResults, Word};
+
+pub fn word(response: &Response<Word>, json: bool) -> Result<(), RequestError> {
+    if json {
+        return raw(response);
+    }
+    let word = response.try_parse()?;
+    println!("{}", word.word);
+    if let Some(ref pronunciation) = word.pronunciation {
+        for (part_of_speech, sounds) in pronunciation {
+            println!("  pronunciation ({}): {}", part_of_speech, sounds);
+        }
+    }
+    if let Some(frequency) = word.frequency {
+        println!("  frequency: {}", frequency);
+    }
+    for (index, entry) in word.entries.iter().enumerate() {
+        println!();
+        print_entry(index + 1, entry);
+    }
+    quota(response);
+    Ok(())
+}
+
+pub fn print_entry(number: usize, entry: &Entry) {
+    match entry.part_of_speech {
+        Some(ref part_of_speech) => {
+            println!("{:>2}. ({}) {}", number, part_of_speech, entry.definition)
+        }
+        None => println!("{:>2}. {}", number, entry.definition),
+    }
+    for (label, words) in relations(entry) {
+        println!("      {}: {}", label, words.join(", "));
+    }
+}
+
+pub fn relations(entry: &Entry) -> Vec<(&'static str, &Vec<String>)> {
+    let relations = vec![
+        ("synonyms", &entry.synonyms),
+        ("antonyms", &entry.antonyms),
+        ("type of", &entry.type_of),
+        ("has types", &entry.has_types),
+        ("part of", &entry.part_of),
+        ("has parts", &entry.has_parts),
+        ("member of", &entry.member_of),
+        ("has substances", &entry.has_substances),
+        ("similar to", &entry.similar_to),
+        ("pertains to", &entry.pertains_to),
+        ("verb group", &entry.verb_group),
+        ("derivation", &entry.derivation),
+        ("examples", &entry.examples),
+    ];
+    relations
+        .into_iter()
+        .filter_map(|(label, words)| words.as_ref().map(|words| (label, words)))
+        .collect()
+}
+
+pub fn value(response: &Response<Value>, json: bool) -> Result<(), RequestError> {
+    if json {
+        return raw(response);
+    }
+    let value = response.try_parse()?;
+    match value {
+        Value::Object(fields) => {
+            if let Some(Value::String(word)) = fields.get("word") {
+                println!("{}", word);
+            }
+            for (name, value) in fields.iter().filter(|(name, _)| *name != "word") {
+                print_value(name, value);
+            }
+        }
+        value => print_value("result", &value),
+    }
+    quota(response);
+    Ok(())
+}
+
+fn print_value(label: &str, value: &Value) {
+    match value {
+        Value::Array(items) if items.iter().all(Value::is_string) => {
+            let items: Vec<&str> = items.iter().filter_map(Value::as_str).collect();
+            println!("  {}: {}", label, items.join(", "));
+        }
+        Value::Array(items) => {
+            println!("  {}:", label);
+            for (index, item) in items.iter().enumerate() {
+                println!("{:>4}. {}", index + 1, describe(item));
+            }
+        }
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                print_value(&format!("{} ({})", label, name), value);
+            }
+        }
+        value => println!("  {}: {}", label, describe(value)),
+    }
+}
+
+fn describe(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.to_owned(),
+        Value::Object(fields) => match (fields.get("definition"), fields.get("partOfSpeech")) {
+            (Some(Value::String(definition)), Some(Value::String(part_of_speech))) => {
+                format!("({}) {}", part_of_speech, definition)
+            }
+            (Some(Value::String(definition)), _) => definition.to_owned(),
+            _ => value.to_string(),
+        },
+        value => value.to_string(),
+    }
+}
+
+pub fn search(response: &Response<SearchResults>, json: bool) -> Result<(), RequestError> {
+    if json {
+        return raw(response);
+    }
+    let results = response.try_parse()?;
+    for word in &results.results.data {
+        println!("{}", word);
+    }
+    println!(
+        "\n{} of {} matches",
+        results.results.data.len(),
+        results.results.total
+    );
+    quota(response);
+    Ok(())
+}
+
+fn raw<T>(response: &Response<T>) -> Result<(), RequestError> {
+    match serde_json::from_str::<Value>(&response.response_json) {
+        Ok(value) => println!("{:#}", value),
+        Err(_) => println!("{}", response.response_json),
+    }
+    eprintln!("{}", quota_line(response));
+    Ok(())
+}
+
+fn quota<T>(response: &Response<T>) {
+    println!("\n{}", quota_line(response));
+}
+
+fn quota_line<T>(response: &Response<T>) -> String {
+    if response.from_cache {
+        return "served from cache".to_owned();
+    }
+    format!(
+        "{} of {} requests remaining",
+        response.rate_limit_remaining, response.rate_limit_requests_limit
+    )
+}
@@ -0,0 +1,231 @@
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::fs;
+use std::path::Path;
+use wordsapi::{Client, Entry, RequestError, Word};
+
+use crate::output;
+
+static HELP: &str = "\
+Type a word to look it up. Its senses are numbered, and these commands
+follow a relation of the current word, for every sense or only sense N:
+
+  :syn [N]        synonyms
+  :ant [N]        antonyms
+  :typeof [N]     what it is a type of
+  :types [N]      types of it
+  :partof [N]     what it is a part of
+  :parts [N]      its parts
+  :memberof [N]   what it is a member of
+  :sim [N]        similar words
+
+  :sense N        everything about sense N
+  :go N           look up word [N] of the last list
+  :back           return to the previous word
+  :help           show this help
+  :quit           leave";
+
+type Relation = fn(&Entry) -> &Option<Vec<String>>;
+
+struct Session<'a> {
+    client: &'a Client,
+    current: Option<Word>,
+    trail: Vec<String>,
+    listed: Vec<String>,
+}
+
+pub fn run(client: &Client, history: &Path) -> rustyline::Result<()> {
+    let mut editor = DefaultEditor::new()?;
+    // There is no history the first time around.
+    let _ = editor.load_history(history);
+    println!("Type a word to look it up, or :help for commands.");
+    let mut session = Session {
+        client,
+        current: None,
+        trail: Vec::new(),
+        listed: Vec::new(),
+    };
+    loop {
+        let prompt = match session.current {
+            Some(ref word) => format!("{}> ", word.word),
+            None => "> ".to_owned(),
+        };
+        match editor.readline(&prompt) {
+            Ok(line) => {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                editor.add_history_entry(line)?;
+                if !session.handle(line) {
+                    break;
+                }
+            }
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => return Err(error),
+        }
+    }
+    if let Some(dir) = history.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    editor.save_history(history)
+}
+
+impl<'a> Session<'a> {
+    /// Runs one line of input, returning false when the user wants to leave.
+    fn handle(&mut self, line: &str) -> bool {
+        if !line.starts_with(':') {
+            self.look_up(line, true);
+            return true;
+        }
+        let mut parts = line[1..].split_whitespace();
+        let command = parts.next().unwrap_or("");
+        let number = parts.next().map(str::parse::<usize>);
+        let number = match number {
+            Some(Ok(n)) => Some(n),
+            Some(Err(_)) => {
+                println!("expected a number after :{}", command);
+                return true;
+            }
+            None => None,
+        };
+        match command {
+            "q" | "quit" | "exit" => return false,
+            "h" | "help" => println!("{}", HELP),
+            "syn" => self.relation("synonyms", |e| &e.synonyms, number),
+            "ant" => self.relation("antonyms", |e| &e.antonyms, number),
+            "typeof" => self.relation("type of", |e| &e.type_of, number),
+            "types" => self.relation("types", |e| &e.has_types, number),
+            "partof" => self.relation("part of", |e| &e.part_of, number),
+            "parts" => self.relation("parts", |e| &e.has_parts, number),
+            "memberof" => self.relation("member of", |e| &e.member_of, number),
+            "sim" => self.relation("similar words", |e| &e.similar_to, number),
+            "sense" => self.sense(number),
+            "go" => self.go(number),
+            "back" => match self.trail.pop() {
+                Some(word) => self.look_up(&word, false),
+                None => println!("there is no previous word"),
+            },
+            _ => println!("unknown command :{}, try :help", command),
+        }
+        true
+    }
+
+    /// Looks up `word` and makes it the current word, remembering the one it
+    /// replaces for `:back` if `remember` is set.
+    fn look_up(&mut self, word: &str, remember: bool) {
+        let response = match self.client.look_up::<Word>(word) {
+            Ok(response) => response,
+            Err(RequestError::NotFound { word }) => {
+                println!("no such word: {}", word);
+                return;
+            }
+            Err(error) => {
+                println!("lookup failed: {}", error);
+                return;
+            }
+        };
+        let word = match response.result {
+            Ok(word) => word,
+            Err(error) => {
+                println!("could not read the reply: {}", error);
+                return;
+            }
+        };
+        for (index, entry) in word.entries.iter().enumerate() {
+            println!("{:>2}. {}", index + 1, summary(entry));
+        }
+        if response.from_cache {
+            println!("(from cache)");
+        } else {
+            println!(
+                "({} of {} requests remaining)",
+                response.rate_limit_remaining, response.rate_limit_requests_limit
+            );
+        }
+        self.listed.clear();
+        if let Some(previous) = self.current.replace(word) {
+            if remember {
+                self.trail.push(previous.word);
+            }
+        }
+    }
+
+    fn relation(&mut self, label: &str, relation: Relation, number: Option<usize>) {
+        let word = match self.current {
+            Some(ref word) => word,
+            None => {
+                println!("look up a word first");
+                return;
+            }
+        };
+        let senses: Vec<(usize, &Entry)> = match number {
+            Some(n) if n >= 1 && n <= word.entries.len() => vec![(n, &word.entries[n - 1])],
+            Some(n) => {
+                println!("{} has no sense {}", word.word, n);
+                return;
+            }
+            None => word
+                .entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| (index + 1, entry))
+                .collect(),
+        };
+        let mut listed = Vec::new();
+        for (n, entry) in senses {
+            if let Some(ref words) = *relation(entry) {
+                println!("{:>2}. {}", n, summary(entry));
+                for related in words {
+                    listed.push(related.clone());
+                    println!("      [{}] {}", listed.len(), related);
+                }
+            }
+        }
+        if listed.is_empty() {
+            println!("no {} for {}", label, word.word);
+        } else {
+            self.listed = listed;
+        }
+    }
+
+    fn sense(&self, number: Option<usize>) {
+        let word = match self.current {
+            Some(ref word) => word,
+            None => {
+                println!("look up a word first");
+                return;
+            }
+        };
+        match number {
+            Some(n) if n >= 1 && n <= word.entries.len() => {
+                output::print_entry(n, &word.entries[n - 1])
+            }
+            Some(n) => println!("{} has no sense {}", word.word, n),
+            None => println!("usage: :sense N"),
+        }
+    }
+
+    fn go(&mut self, number: Option<usize>) {
+        let word = match number {
+            Some(n) if n >= 1 && n <= self.listed.len() => self.listed[n - 1].clone(),
+            Some(n) => {
+                println!("there is no word [{}] in the last list", n);
+                return;
+            }
+            None => {
+                println!("usage: :go N");
+                return;
+            }
+        };
+        self.look_up(&word, true);
+    }
+}
+
+fn summary(entry: &Entry) -> String {
+    match entry.part_of_speech {
+        Some(ref part_of_speech) => format!("({}) {}", part_of_speech, entry.definition),
+        None => entry.definition.clone(),
+    }
+}
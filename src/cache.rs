@@ -0,0 +1,274 @@
+use hyper::{HeaderMap, StatusCode};
+use log::warn;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::client::RawResponse;
+
+/// API replies kept by request URL, so that repeated lookups do not spend
+/// quota. Only successful and "word not found" replies are kept.
+///
+/// At most `capacity` replies are held in memory; past that, the least
+/// recently used one is dropped. A cache made with `on_disk` also writes
+/// every reply to its directory and reads replies back from there, so they
+/// survive the process.
+pub struct Cache {
+    responses: Mutex<Memory>,
+    capacity: usize,
+    dir: Option<PathBuf>,
+}
+
+/// The number of replies a cache holds in memory unless told otherwise.
+pub const DEFAULT_CAPACITY: usize = 10_000;
+
+/// Replies by URL, each with the tick it was last used at, and the URLs by
+/// that tick, oldest first.
+#[derive(Default)]
+struct Memory {
+    responses: HashMap<String, (RawResponse, u64)>,
+    by_use: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Memory {
+    fn get(&mut self, url: &str) -> Option<RawResponse> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (response, used) = self.responses.get_mut(url)?;
+        self.by_use.remove(used);
+        self.by_use.insert(tick, url.to_owned());
+        *used = tick;
+        Some(response.clone())
+    }
+
+    fn insert(&mut self, url: &str, response: RawResponse, capacity: usize) {
+        self.tick += 1;
+        if let Some((_, used)) = self.responses.insert(url.to_owned(), (response, self.tick)) {
+            self.by_use.remove(&used);
+        }
+        self.by_use.insert(self.tick, url.to_owned());
+        while self.responses.len() > capacity {
+            let oldest = match self.by_use.keys().next() {
+                Some(&oldest) => oldest,
+                None => break,
+            };
+            if let Some(url) = self.by_use.remove(&oldest) {
+                self.responses.remove(&url);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.responses.clear();
+        self.by_use.clear();
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self {
+            responses: Mutex::new(Memory::default()),
+            capacity: DEFAULT_CAPACITY,
+            dir: None,
+        }
+    }
+}
+
+/// A reply as written to disk. Headers other than the rate limits are not kept.
+#[derive(Serialize, Deserialize)]
+struct StoredResponse {
+    url: String,
+    status: u16,
+    allowed: usize,
+    remaining: usize,
+    body: String,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A cache that persists replies in `dir`, creating it if needed.
+    pub fn on_disk<P: Into<PathBuf>>(dir: P) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: Some(dir),
+            ..Self::default()
+        })
+    }
+
+    /// How many replies to hold in memory. Defaults to `DEFAULT_CAPACITY`.
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    pub fn dir(&self) -> Option<&Path> {
+        self.dir.as_deref()
+    }
+
+    /// The number of replies held in memory.
+    pub fn len(&self) -> usize {
+        self.responses.lock().unwrap().responses.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn contains(&self, url: &str) -> bool {
+        self.responses.lock().unwrap().responses.contains_key(url) || self.read(url).is_some()
+    }
+
+    /// Forgets the replies held in memory. Files on disk are kept.
+    pub fn clear(&self) {
+        self.responses.lock().unwrap().clear();
+    }
+
+    pub(crate) fn get(&self, url: &str) -> Option<RawResponse> {
+        if let Some(response) = self.responses.lock().unwrap().get(url) {
+            return Some(response);
+        }
+        let response = self.read(url)?;
+        self.responses
+            .lock()
+            .unwrap()
+            .insert(url, response.clone(), self.capacity);
+        Some(response)
+    }
+
+    pub(crate) fn insert(&self, url: &str, response: RawResponse) {
+        if let Err(e) = self.write(url, &response) {
+            warn!("could not write {} to the cache: {}", url, e);
+        }
+        self.responses
+            .lock()
+            .unwrap()
+            .insert(url, response, self.capacity);
+    }
+
+    fn read(&self, url: &str) -> Option<RawResponse> {
+        let path = self.dir.as_ref()?.join(file_name(url));
+        let stored: StoredResponse = serde_json::from_slice(&fs::read(path).ok()?).ok()?;
+        if stored.url != url {
+            return None;
+        }
+        Some(RawResponse {
+            body: stored.body,
+            allowed: stored.allowed,
+            remaining: stored.remaining,
+            status: StatusCode::from_u16(stored.status).ok()?,
+            headers: HeaderMap::new(),
+        })
+    }
+
+    fn write(&self, url: &str, response: &RawResponse) -> io::Result<()> {
+        let dir = match self.dir {
+            Some(ref dir) => dir,
+            None => return Ok(()),
+        };
+        let stored = StoredResponse {
+            url: url.to_owned(),
+            status: response.status.as_u16(),
+            allowed: response.allowed,
+            remaining: response.remaining,
+            body: response.body.clone(),
+        };
+        fs::write(dir.join(file_name(url)), serde_json::to_vec(&stored)?)
+    }
+}
+
+/// URLs can be longer than a file name may be, so files are named after a
+/// 64-bit FNV-1a hash of the URL, which is stable across builds.
+fn file_name(url: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in url.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+    format!("{:016x}.json", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cache::file_name;
+    use crate::client::RawResponse;
+    use crate::Cache;
+    use hyper::{HeaderMap, StatusCode};
+    use std::env;
+    use std::fs;
+
+    fn raw(body: &str) -> RawResponse {
+        RawResponse {
+            body: body.to_owned(),
+            allowed: 2500,
+            remaining: 2499,
+            status: StatusCode::OK,
+            headers: HeaderMap::new(),
+        }
+    }
+
+    #[test]
+    fn it_starts_empty() {
+        let cache = Cache::new();
+        assert!(cache.is_empty());
+        assert!(cache.get("https://example.com/words/cat").is_none());
+    }
+
+    #[test]
+    fn it_returns_inserted_responses() {
+        let cache = Cache::new();
+        cache.insert("https://example.com/words/cat", raw("{}"));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains("https://example.com/words/cat"));
+        assert_eq!(
+            cache.get("https://example.com/words/cat").unwrap().body,
+            "{}"
+        );
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn it_drops_the_least_recently_used_response() {
+        let cache = Cache::new().with_capacity(2);
+        cache.insert("https://example.com/words/cat", raw("cat"));
+        cache.insert("https://example.com/words/dog", raw("dog"));
+        assert!(cache.get("https://example.com/words/cat").is_some());
+        cache.insert("https://example.com/words/eel", raw("eel"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.contains("https://example.com/words/cat"));
+        assert!(!cache.contains("https://example.com/words/dog"));
+        assert!(cache.contains("https://example.com/words/eel"));
+    }
+
+    #[test]
+    fn it_names_files_by_url_hash() {
+        assert_eq!(file_name(""), "cbf29ce484222325.json");
+        assert_ne!(
+            file_name("https://example.com/words/cat"),
+            file_name("https://example.com/words/cats")
+        );
+    }
+
+    #[test]
+    fn it_persists_responses_on_disk() {
+        let dir = env::temp_dir().join(format!("wordsapi-cache-test-{}", std::process::id()));
+        let url = "https://example.com/words/dog";
+        Cache::on_disk(&dir)
+            .unwrap()
+            .insert(url, raw(r#"{"word":"dog"}"#));
+        let cache = Cache::on_disk(&dir).unwrap();
+        assert!(cache.is_empty());
+        let response = cache.get(url).unwrap();
+        assert_eq!(response.body, r#"{"word":"dog"}"#);
+        assert_eq!(response.remaining, 2499);
+        assert_eq!(cache.len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
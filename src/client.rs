@@ -1,32 +1,108 @@
 use hyper::client::HttpConnector;
 use hyper::error::Error;
 use hyper::header::HeaderName;
+use hyper::header::HeaderValue;
 use hyper::rt::Future;
 use hyper::rt::Stream;
 use hyper::Body;
+use hyper::HeaderMap;
 use hyper::Request;
+use hyper::StatusCode;
 use hyper_tls::HttpsConnector;
-use log::{debug, error, trace};
+use log::{debug, error, trace, warn};
 use serde::de::DeserializeOwned;
+use std::fmt;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
 use tokio_core::reactor::Core;
 
+use crate::normalize::{percent_encode, validate};
+use crate::response::is_word_not_found;
+use crate::secret::redact_headers;
+use crate::Cache;
+use crate::Config;
+use crate::ConfigError;
 use crate::HasRequestType;
+use crate::Normalization;
 use crate::RequestError;
 use crate::RequestType;
 use crate::Response;
+use crate::RetryPolicy;
+use crate::SearchQuery;
+use crate::SearchResults;
+use crate::Secret;
+use crate::Word;
 
 static X_RATE_LIMIT_REMAINING: &[u8] = b"x-ratelimit-requests-remaining";
 static X_RATE_LIMIT_REQUESTS_LIMIT: &[u8] = b"x-ratelimit-requests-limit";
 static X_MASHAPE_KEY: &[u8] = b"x-mashape-key";
 static X_MASHAPE_HOST: &[u8] = b"x-mashape-host";
-static API_BASE: &'static str = "https://wordsapiv1.p.mashape.com/words/";
-static MASHAPE_HOST: &'static str = "wordsapiv1.p.mashape.com";
+static API_BASE: &str = "https://wordsapiv1.p.mashape.com/words/";
+static MASHAPE_HOST: &str = "wordsapiv1.p.mashape.com";
 
 pub struct Client {
     https_client: hyper::Client<HttpsConnector<HttpConnector>, Body>,
     api_base: String,
-    api_token: String,
+    api_token: Secret,
     mashape_host: String,
+    lenient: bool,
+    normalization: Option<Normalization>,
+    cache: Option<Cache>,
+    retry_policy: RetryPolicy,
+    min_request_interval: Option<Duration>,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("api_base", &self.api_base)
+            .field("api_token", &self.api_token)
+            .field("mashape_host", &self.mashape_host)
+            .field("lenient", &self.lenient)
+            .field("normalization", &self.normalization)
+            .field("cache_dir", &self.cache.as_ref().and_then(Cache::dir))
+            .field("retry_policy", &self.retry_policy)
+            .field("min_request_interval", &self.min_request_interval)
+            .finish()
+    }
+}
+
+/// A reply from the API, before it is parsed.
+#[derive(Clone)]
+pub(crate) struct RawResponse {
+    pub(crate) body: String,
+    pub(crate) allowed: usize,
+    pub(crate) remaining: usize,
+    pub(crate) status: StatusCode,
+    pub(crate) headers: HeaderMap,
+}
+
+impl RawResponse {
+    fn is_word_not_found(&self) -> bool {
+        self.status == StatusCode::NOT_FOUND && is_word_not_found(&self.body)
+    }
+
+    /// Successful replies and definitive "word not found" replies are worth
+    /// keeping; anything else may be transient.
+    fn is_cacheable(&self) -> bool {
+        self.status.is_success() || self.is_word_not_found()
+    }
+}
+
+/// The wait between requests for a rate limit, or `None` for no limit. It is
+/// an error if the rate is NaN or the wait does not fit in a `Duration`.
+pub(crate) fn request_interval(requests_per_second: f64) -> Result<Option<Duration>, ()> {
+    if requests_per_second.is_nan() || requests_per_second < 0.0 {
+        Err(())
+    } else if requests_per_second == 0.0 {
+        Ok(None)
+    } else {
+        Duration::try_from_secs_f64(1.0 / requests_per_second)
+            .map(Some)
+            .map_err(|_| ())
+    }
 }
 
 impl Client {
@@ -36,30 +112,255 @@ impl Client {
         Self {
             https_client: client,
             api_base: API_BASE.to_owned(),
-            api_token: token.to_owned(),
+            api_token: Secret::new(token),
             mashape_host: MASHAPE_HOST.to_owned(),
+            lenient: false,
+            normalization: None,
+            cache: None,
+            retry_policy: RetryPolicy::default(),
+            min_request_interval: None,
+            last_request: Mutex::new(None),
         }
     }
 
+    /// Builds a client from the named profile of the configuration file. See
+    /// `Config` for the file format and environment overrides.
+    pub fn from_profile(name: &str) -> Result<Self, ConfigError> {
+        Config::load()?.profile(Some(name))?.client()
+    }
+
+    /// Send requests to another API base URL, such as a proxy. It should end
+    /// with the `/words/` path.
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.api_base = base_url.to_owned();
+        self
+    }
+
+    /// The API gateway host sent with every request, for keys issued by a
+    /// marketplace other than the default.
+    pub fn with_gateway(mut self, host: &str) -> Self {
+        self.mashape_host = host.to_owned();
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Wait between requests so that no more than `requests_per_second` are
+    /// sent. Cached responses are not limited. A rate of zero means no limit,
+    /// and a negative rate or one too small to wait for is ignored.
+    pub fn with_rate_limit(mut self, requests_per_second: f64) -> Self {
+        self.min_request_interval = match request_interval(requests_per_second) {
+            Ok(interval) => interval,
+            Err(()) => {
+                warn!(
+                    "ignoring rate limit of {} requests per second",
+                    requests_per_second
+                );
+                None
+            }
+        };
+        self
+    }
+
+    /// Parse responses leniently: fields this crate does not model are kept in
+    /// `extra`, and fields with an unexpected type are dropped and reported in
+    /// `Response::warnings` instead of failing the whole result.
+    pub fn with_lenient_parsing(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Normalize every word before it is validated and looked up.
+    pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = Some(normalization);
+        self
+    }
+
+    /// Serve repeated requests from `cache` instead of the API.
+    pub fn with_cache(mut self, cache: Cache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub fn cache(&self) -> Option<&Cache> {
+        self.cache.as_ref()
+    }
+
     pub fn look_up<T: DeserializeOwned + HasRequestType>(
         &self,
         word: &str,
+    ) -> Result<Response<T>, RequestError> {
+        self.look_up_as::<T>(word, &T::request_type())
+    }
+
+    /// Looks up `word` at the endpoint for `request_type`, parsing the reply
+    /// as `T`. Useful for endpoints without a typed model, with
+    /// `T = serde_json::Value`.
+    pub fn look_up_as<T: DeserializeOwned>(
+        &self,
+        word: &str,
+        request_type: &RequestType,
     ) -> Result<Response<T>, RequestError> {
         // TODO return future
         trace!("looking up {}", word);
-        let uri = self.request_url(word, &T::request_type());
+        let word = self.prepare_word(word)?;
+        let uri = self.request_url(&word, request_type);
+        self.get(uri, Some(&word), true)
+    }
+
+    pub fn search(&self, query: &SearchQuery) -> Result<Response<SearchResults>, RequestError> {
+        trace!("searching {:?}", query);
+        let uri = format!("{}?{}", self.api_base, query.query_string());
+        self.get(uri, None, true)
+    }
+
+    /// A random word. Random words are never cached.
+    pub fn random(&self) -> Result<Response<Word>, RequestError> {
+        self.random_matching(&SearchQuery::new())
+    }
+
+    /// A random word matching the query's filters, such as `frequency_min`
+    /// or `has_details`. Its paging options are ignored.
+    pub fn random_matching(&self, query: &SearchQuery) -> Result<Response<Word>, RequestError> {
+        trace!("looking up a random word matching {:?}", query);
+        let mut query = query.clone();
+        query.limit = None;
+        query.page = None;
+        let uri = match query.query_string().as_str() {
+            "" => format!("{}?random=true", self.api_base),
+            filters => format!("{}?random=true&{}", self.api_base, filters),
+        };
+        self.get(uri, None, false)
+    }
+
+    fn get<T: DeserializeOwned>(
+        &self,
+        uri: String,
+        word: Option<&str>,
+        cacheable: bool,
+    ) -> Result<Response<T>, RequestError> {
+        let started = Instant::now();
+        let cache = self.cache.as_ref().filter(|_| cacheable);
+        let cached = cache.and_then(|cache| cache.get(&uri));
+        let from_cache = cached.is_some();
+        let mut retries = 0;
+        let raw = match cached {
+            Some(raw) => raw,
+            None => {
+                // A key that cannot be sent will not work on a retry either.
+                let key = self.api_key()?;
+                let raw = loop {
+                    self.throttle();
+                    let result = self.fetch(self.request(&uri, &key));
+                    // `fetch` only fails on network errors, which may pass.
+                    let transient = match result {
+                        Ok(ref raw) => {
+                            raw.status == StatusCode::TOO_MANY_REQUESTS
+                                || raw.status.is_server_error()
+                        }
+                        Err(_) => true,
+                    };
+                    if !transient || retries >= self.retry_policy.max_retries {
+                        break result?;
+                    }
+                    let delay = self.retry_policy.delay(retries);
+                    warn!("retrying GET {} in {:?}", uri, delay);
+                    thread::sleep(delay);
+                    retries += 1;
+                };
+                if let Some(cache) = cache {
+                    if raw.is_cacheable() {
+                        cache.insert(&uri, raw.clone());
+                    }
+                }
+                raw
+            }
+        };
+        let elapsed = started.elapsed();
+        debug!(
+            "GET {} returned {} in {:?}{}",
+            uri,
+            raw.status,
+            elapsed,
+            if from_cache { " from cache" } else { "" }
+        );
+        if let Some(word) = word {
+            if raw.is_word_not_found() {
+                return Err(RequestError::NotFound {
+                    word: word.to_owned(),
+                });
+            }
+        }
+        // The stored count was only current when the reply was first fetched.
+        let remaining = if from_cache { 0 } else { raw.remaining };
+        let mut response = if self.lenient {
+            Response::new_lenient(raw.body, raw.allowed, remaining)
+        } else {
+            Response::new(raw.body, raw.allowed, remaining)
+        };
+        response.status = raw.status.as_u16();
+        response.headers = raw.headers;
+        response.request_url = uri;
+        response.elapsed = elapsed;
+        response.from_cache = from_cache;
+        response.retries = retries;
+        if response.result.is_err() {
+            error!(
+                "could not parse the {} response from GET {}",
+                response.status, response.request_url
+            );
+        }
+        Ok(response)
+    }
+
+    fn throttle(&self) {
+        let interval = match self.min_request_interval {
+            Some(interval) => interval,
+            None => return,
+        };
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let since = last.elapsed();
+            if since < interval {
+                thread::sleep(interval - since);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+
+    fn api_key(&self) -> Result<HeaderValue, RequestError> {
+        let mut key = HeaderValue::from_str(self.api_token.expose()).map_err(|_| {
+            error!("the API key is not a valid header value");
+            RequestError::RequestError
+        })?;
+        key.set_sensitive(true);
+        Ok(key)
+    }
+
+    fn request(&self, uri: &str, key: &HeaderValue) -> Request<Body> {
         let request = Request::builder()
             .method("GET")
             .uri(uri)
-            .header(X_MASHAPE_KEY, self.api_token.to_owned())
+            .header(X_MASHAPE_KEY, key.clone())
             .header(X_MASHAPE_HOST, self.mashape_host.to_owned())
             .body(Body::empty())
             .unwrap();
+        trace!("GET {} {}", uri, redact_headers(request.headers()));
+        request
+    }
+
+    fn fetch(&self, request: Request<Body>) -> Result<RawResponse, RequestError> {
         let work = self
             .https_client
             .request(request)
             .and_then(|response| {
                 debug!("the api responded");
+                trace!("response headers {}", redact_headers(response.headers()));
+                let status = response.status();
+                let headers = response.headers().clone();
                 let remaining = response
                     .headers()
                     .get(HeaderName::from_lowercase(X_RATE_LIMIT_REMAINING).unwrap())
@@ -73,12 +374,12 @@ impl Client {
                 response
                     .into_body()
                     .concat2()
-                    .map(move |body| {
-                        (
-                            String::from_utf8(body.to_vec()).unwrap(),
-                            allowed,
-                            remaining,
-                        )
+                    .map(move |body| RawResponse {
+                        body: String::from_utf8(body.to_vec()).unwrap(),
+                        allowed,
+                        remaining,
+                        status,
+                        headers,
                     })
                     .map_err(Error::from)
             })
@@ -87,60 +388,88 @@ impl Client {
                 Err(RequestError::RequestError)
             });
         let mut reactor = Core::new().unwrap();
-        let result = reactor.run(work);
-        match result {
-            Ok(r) => Ok(Response::new(r.0, r.1, r.2)),
+        match reactor.run(work) {
+            Ok(raw) => Ok(raw),
             Err(e) => e,
         }
     }
 
+    fn prepare_word(&self, word: &str) -> Result<String, RequestError> {
+        let word = match self.normalization {
+            Some(ref normalization) => normalization.apply(word),
+            None => word.to_owned(),
+        };
+        validate(&word)?;
+        Ok(word)
+    }
+
     fn request_url(&self, word: &str, request_type: &RequestType) -> String {
         let suffix = match *request_type {
-            RequestType::Word => "",
-            RequestType::Definitions => "/definitions",
-            RequestType::Synonyms => "/synonyms",
-            RequestType::Antonyms => "/antonyms",
-            RequestType::Examples => "/examples",
-            RequestType::Rhymes => "/rhymes",
-            RequestType::Frequency => "/frequency",
-            RequestType::IsATypeOf => "/isATypeOf",
-            RequestType::HasTypes => "/hasTypes",
-            RequestType::PartOf => "/partOf",
-            RequestType::HasParts => "/hasParts",
-            RequestType::IsAnInstanceOf => "/isAnInstanceOf",
-            RequestType::HasInstances => "/hasInstances",
-            RequestType::InRegion => "/inRegion",
-            RequestType::RegionOf => "/regionOf",
-            RequestType::UsageOf => "/usageOf",
-            RequestType::HasUsages => "/hasUsages",
-            RequestType::IsAMemberOf => "/isAMemberOf",
-            RequestType::HasMembers => "/hasMembers",
-            RequestType::IsASubstanceOf => "/isASubstanceOf",
-            RequestType::HasSubstances => "/hasSubstances",
-            RequestType::HasAttribute => "/hasAttribute",
-            RequestType::InCategory => "/inCategory",
-            RequestType::HasCategories => "/hasCategories",
-            RequestType::Also => "/also",
-            RequestType::PertainsTo => "/pertainsTo",
-            RequestType::SimilarTo => "/similarTo",
-            RequestType::Entails => "/entails",
+            RequestType::Word => String::new(),
+            _ => format!("/{}", request_type.name()),
         };
-        format!("{}{}{}", self.api_base, word, suffix)
+        format!("{}{}{}", self.api_base, percent_encode(word), suffix)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::client::RawResponse;
     use crate::client::API_BASE;
     use crate::client::MASHAPE_HOST;
+    use crate::Cache;
     use crate::Client;
+    use crate::Normalization;
     use crate::RequestType;
+    use crate::RetryPolicy;
+    use crate::Word;
+    use hyper::{HeaderMap, StatusCode};
+    use log::{LevelFilter, Log, Metadata, Record};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    static LOGGED: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct CapturingLogger;
+
+    impl Log for CapturingLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            LOGGED.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CapturingLogger = CapturingLogger;
 
     #[test]
     fn it_has_api_token() {
         let token = "TEST_TOKEN";
         let word_client = Client::new(token);
-        assert_eq!(word_client.api_token, token);
+        assert_eq!(word_client.api_token.expose(), token);
+    }
+
+    #[test]
+    fn it_never_logs_api_token() {
+        let _ = log::set_logger(&LOGGER);
+        log::set_max_level(LevelFilter::Trace);
+        let token = "TEST_TOKEN_NEVER_LOGGED";
+        let word_client = Client::new(token);
+        let request_uri = word_client.request_url("example", &RequestType::Word);
+        let key = word_client.api_key().unwrap();
+        let request = word_client.request(&request_uri, &key);
+        log::debug!("{:?}", word_client);
+        log::debug!("{:?}", request);
+        let logged = LOGGED.lock().unwrap();
+        assert!(logged
+            .iter()
+            .any(|line| line.contains("x-mashape-key: ***")));
+        assert!(logged.iter().any(|line| line.contains("api_token: ***")));
+        assert!(logged.iter().all(|line| !line.contains(token)));
     }
 
     #[test]
@@ -157,6 +486,83 @@ mod tests {
         assert_eq!(word_client.mashape_host, MASHAPE_HOST);
     }
 
+    #[test]
+    fn it_has_gateway_and_base_url() {
+        let token = "TEST_TOKEN";
+        let word_client = Client::new(token)
+            .with_gateway("wordsapiv1.p.rapidapi.com")
+            .with_base_url("https://wordsapiv1.p.rapidapi.com/words/");
+        assert_eq!(word_client.mashape_host, "wordsapiv1.p.rapidapi.com");
+        assert_eq!(
+            word_client.request_url("example", &RequestType::Rhymes),
+            "https://wordsapiv1.p.rapidapi.com/words/example/rhymes"
+        );
+    }
+
+    #[test]
+    fn it_has_rate_limit() {
+        let token = "TEST_TOKEN";
+        let word_client = Client::new(token);
+        assert_eq!(word_client.min_request_interval, None);
+        let word_client = word_client.with_rate_limit(4.0);
+        assert_eq!(
+            word_client.min_request_interval,
+            Some(Duration::from_millis(250))
+        );
+        let word_client = word_client.with_rate_limit(0.0);
+        assert_eq!(word_client.min_request_interval, None);
+    }
+
+    #[test]
+    fn it_does_not_retry_an_unusable_key() {
+        let word_client = Client::new("TEST\nTOKEN")
+            .with_retry_policy(RetryPolicy::new(3, Duration::from_secs(10)));
+        let started = Instant::now();
+        assert!(word_client.look_up::<Word>("example").is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn it_ignores_unusable_rate_limits() {
+        for rate in &[f64::NAN, -1.0, 1e-300, f64::MIN_POSITIVE] {
+            let word_client = Client::new("TEST_TOKEN").with_rate_limit(*rate);
+            assert_eq!(word_client.min_request_interval, None);
+        }
+    }
+
+    #[test]
+    fn it_serves_cached_responses_without_a_quota() {
+        let cache = Cache::new();
+        cache.insert(
+            &format!("{}cat", API_BASE),
+            RawResponse {
+                body: r#"{"word": "cat", "results": []}"#.to_owned(),
+                allowed: 2500,
+                remaining: 2400,
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+            },
+        );
+        let word_client = Client::new("TEST_TOKEN").with_cache(cache);
+        let response = word_client.look_up::<Word>("cat").unwrap();
+        assert!(response.from_cache);
+        assert_eq!(response.status, 200);
+        assert_eq!(response.request_url, format!("{}cat", API_BASE));
+        assert_eq!(response.retries, 0);
+        assert!(response.warnings.is_empty());
+        assert_eq!(response.result.unwrap().word, "cat");
+        assert_eq!(response.rate_limit_remaining, 0);
+        assert_eq!(response.rate_limit_requests_limit, 2500);
+    }
+
+    #[test]
+    fn it_is_strict_by_default() {
+        let token = "TEST_TOKEN";
+        let word_client = Client::new(token);
+        assert!(!word_client.lenient);
+        assert!(word_client.with_lenient_parsing(true).lenient);
+    }
+
     #[test]
     fn it_makes_uri_everything() {
         let token = "TEST_TOKEN";
@@ -169,6 +575,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_makes_uri_phrase() {
+        let token = "TEST_TOKEN";
+        let word_client = Client::new(token);
+        let word = "ice cream";
+        let request_uri = word_client.request_url(word, &RequestType::Definitions);
+        assert_eq!(
+            request_uri,
+            "https://wordsapiv1.p.mashape.com/words/ice%20cream/definitions"
+        );
+    }
+
+    #[test]
+    fn it_makes_uri_slash() {
+        let token = "TEST_TOKEN";
+        let word_client = Client::new(token);
+        let word = "and/or";
+        let request_uri = word_client.request_url(word, &RequestType::Word);
+        assert_eq!(
+            request_uri,
+            "https://wordsapiv1.p.mashape.com/words/and%2For"
+        );
+    }
+
+    #[test]
+    fn it_prepares_words() {
+        let token = "TEST_TOKEN";
+        let word_client = Client::new(token);
+        assert_eq!(
+            word_client.prepare_word(" Ice  cream ").unwrap(),
+            " Ice  cream "
+        );
+        assert!(word_client.prepare_word(" ").is_err());
+        let word_client = word_client.with_normalization(Normalization {
+            lowercase: true,
+            ..Normalization::default()
+        });
+        assert_eq!(
+            word_client.prepare_word(" Ice  cream ").unwrap(),
+            "ice cream"
+        );
+    }
+
     #[test]
     fn it_makes_uri_definitions() {
         let token = "TEST_TOKEN";
@@ -492,5 +941,4 @@ mod tests {
             "https://wordsapiv1.p.mashape.com/words/example/entails"
         );
     }
-
 }
@@ -0,0 +1,330 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::client::request_interval;
+use crate::Cache;
+use crate::Client;
+use crate::ConfigError;
+use crate::RetryPolicy;
+use crate::Secret;
+
+static PROFILE_VAR: &str = "WORDSAPI_PROFILE";
+static TOKEN_VAR: &str = "WORDSAPI_TOKEN";
+static GATEWAY_VAR: &str = "WORDSAPI_GATEWAY";
+static BASE_URL_VAR: &str = "WORDSAPI_BASE_URL";
+static CACHE_DIR_VAR: &str = "WORDSAPI_CACHE_DIR";
+static DEFAULT_PROFILE: &str = "default";
+
+/// Named client profiles, read from `wordsapi/config.toml` in the XDG config
+/// directory.
+///
+/// ```toml
+/// default_profile = "prod"
+///
+/// [profiles.prod]
+/// token = "..."
+/// gateway = "wordsapiv1.p.rapidapi.com"
+/// base_url = "https://wordsapiv1.p.rapidapi.com/words/"
+/// cache_dir = "~/.cache/wordsapi"
+/// requests_per_second = 5.0
+/// retry = { max_retries = 3, backoff_ms = 250 }
+/// ```
+///
+/// `WORDSAPI_PROFILE` picks the profile when none is named, and
+/// `WORDSAPI_TOKEN`, `WORDSAPI_GATEWAY`, `WORDSAPI_BASE_URL` and
+/// `WORDSAPI_CACHE_DIR` override the profile's settings.
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    pub default_profile: Option<String>,
+    #[serde(default)]
+    pub profiles: HashMap<String, Profile>,
+}
+
+#[derive(Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    #[serde(skip)]
+    pub name: String,
+    pub token: Option<Secret>,
+    /// The API gateway host, sent with every request.
+    pub gateway: Option<String>,
+    pub base_url: Option<String>,
+    pub cache_dir: Option<PathBuf>,
+    /// Zero means no limit.
+    pub requests_per_second: Option<f64>,
+    pub retry: Option<RetryPolicy>,
+}
+
+impl Config {
+    /// `$XDG_CONFIG_HOME/wordsapi/config.toml`, or
+    /// `~/.config/wordsapi/config.toml` when `XDG_CONFIG_HOME` is not set.
+    pub fn path() -> PathBuf {
+        let config_home = match env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None => home_dir().join(".config"),
+        };
+        config_home.join("wordsapi").join("config.toml")
+    }
+
+    /// Loads the configuration file, or an empty configuration if there is none.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = Self::path();
+        if path.exists() {
+            Self::from_file(&path)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        Self::from_toml(&fs::read_to_string(path)?)
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self, ConfigError> {
+        toml::from_str(toml).map_err(|e| ConfigError::Parse(e.to_string()))
+    }
+
+    /// The profile called `name`, or the default profile, with environment
+    /// overrides applied.
+    pub fn profile(&self, name: Option<&str>) -> Result<Profile, ConfigError> {
+        self.profile_with_env(name, |var| env::var(var).ok())
+    }
+
+    fn profile_with_env<F>(&self, name: Option<&str>, var: F) -> Result<Profile, ConfigError>
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let chosen = name
+            .map(str::to_owned)
+            .or_else(|| var(PROFILE_VAR))
+            .or_else(|| self.default_profile.clone());
+        let mut profile = match chosen {
+            Some(name) => match self.profiles.get(&name) {
+                Some(profile) => Profile {
+                    name,
+                    ..profile.clone()
+                },
+                None => return Err(ConfigError::UnknownProfile(name)),
+            },
+            // Without any profile, settings can still come from the environment.
+            None => Profile {
+                name: DEFAULT_PROFILE.to_owned(),
+                ..self
+                    .profiles
+                    .get(DEFAULT_PROFILE)
+                    .cloned()
+                    .unwrap_or_default()
+            },
+        };
+        if let Some(token) = var(TOKEN_VAR) {
+            profile.token = Some(Secret::new(&token));
+        }
+        if let Some(gateway) = var(GATEWAY_VAR) {
+            profile.gateway = Some(gateway);
+        }
+        if let Some(base_url) = var(BASE_URL_VAR) {
+            profile.base_url = Some(base_url);
+        }
+        if let Some(cache_dir) = var(CACHE_DIR_VAR) {
+            profile.cache_dir = Some(PathBuf::from(cache_dir));
+        }
+        if let Some(requests_per_second) = profile.requests_per_second {
+            if request_interval(requests_per_second).is_err() {
+                return Err(ConfigError::InvalidRateLimit {
+                    profile: profile.name,
+                    requests_per_second,
+                });
+            }
+        }
+        Ok(profile)
+    }
+}
+
+impl Profile {
+    /// Builds a client with every setting of this profile.
+    pub fn client(&self) -> Result<Client, ConfigError> {
+        let token = match self.token {
+            Some(ref token) => token,
+            None => return Err(ConfigError::MissingToken(self.name.clone())),
+        };
+        let mut client = Client::new(token.expose());
+        if let Some(ref gateway) = self.gateway {
+            client = client.with_gateway(gateway);
+        }
+        if let Some(ref base_url) = self.base_url {
+            client = client.with_base_url(base_url);
+        }
+        if let Some(ref cache_dir) = self.cache_dir {
+            client = client.with_cache(Cache::on_disk(expand_home(cache_dir))?);
+        }
+        if let Some(requests_per_second) = self.requests_per_second {
+            client = client.with_rate_limit(requests_per_second);
+        }
+        if let Some(ref retry) = self.retry {
+            client = client.with_retry_policy(retry.clone());
+        }
+        Ok(client)
+    }
+}
+
+fn home_dir() -> PathBuf {
+    PathBuf::from(env::var_os("HOME").unwrap_or_default())
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match path.strip_prefix("~") {
+        Ok(rest) => home_dir().join(rest),
+        Err(_) => path.to_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::expand_home;
+    use crate::Config;
+    use crate::ConfigError;
+    use crate::RetryPolicy;
+    use crate::Secret;
+    use std::collections::HashMap;
+    use std::path::Path;
+
+    static CONFIG_TOML: &str = r#"
+        default_profile = "dev"
+
+        [profiles.dev]
+        token = "DEV_TOKEN"
+
+        [profiles.prod]
+        token = "PROD_TOKEN"
+        gateway = "wordsapiv1.p.rapidapi.com"
+        requests_per_second = 5.0
+        retry = { max_retries = 3 }
+    "#;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |var| vars.get(var).cloned()
+    }
+
+    #[test]
+    fn it_reads_profiles() {
+        let config = Config::from_toml(CONFIG_TOML).unwrap();
+        let profile = config.profile_with_env(Some("prod"), env(&[])).unwrap();
+        assert_eq!(profile.name, "prod");
+        assert_eq!(
+            profile.token.as_ref().map(Secret::expose),
+            Some("PROD_TOKEN")
+        );
+        assert_eq!(
+            profile.gateway.as_deref(),
+            Some("wordsapiv1.p.rapidapi.com")
+        );
+        assert_eq!(profile.requests_per_second, Some(5.0));
+        assert_eq!(
+            profile.retry,
+            Some(RetryPolicy {
+                max_retries: 3,
+                backoff_ms: 500
+            })
+        );
+    }
+
+    #[test]
+    fn it_picks_the_default_profile() {
+        let config = Config::from_toml(CONFIG_TOML).unwrap();
+        let profile = config.profile_with_env(None, env(&[])).unwrap();
+        assert_eq!(profile.name, "dev");
+        let profile = config
+            .profile_with_env(None, env(&[("WORDSAPI_PROFILE", "prod")]))
+            .unwrap();
+        assert_eq!(profile.name, "prod");
+    }
+
+    #[test]
+    fn it_applies_env_overrides() {
+        let config = Config::from_toml(CONFIG_TOML).unwrap();
+        let profile = config
+            .profile_with_env(
+                Some("prod"),
+                env(&[
+                    ("WORDSAPI_TOKEN", "ENV_TOKEN"),
+                    ("WORDSAPI_CACHE_DIR", "/tmp/words"),
+                ]),
+            )
+            .unwrap();
+        assert_eq!(
+            profile.token.as_ref().map(Secret::expose),
+            Some("ENV_TOKEN")
+        );
+        assert_eq!(profile.cache_dir.as_deref(), Some(Path::new("/tmp/words")));
+    }
+
+    #[test]
+    fn it_works_from_env_alone() {
+        let config = Config::default();
+        let profile = config
+            .profile_with_env(None, env(&[("WORDSAPI_TOKEN", "ENV_TOKEN")]))
+            .unwrap();
+        assert_eq!(
+            profile.token.as_ref().map(Secret::expose),
+            Some("ENV_TOKEN")
+        );
+        assert!(config
+            .profile_with_env(None, env(&[]))
+            .unwrap()
+            .client()
+            .is_err());
+    }
+
+    #[test]
+    fn it_rejects_unknown_profiles() {
+        let config = Config::from_toml(CONFIG_TOML).unwrap();
+        match config.profile_with_env(Some("staging"), env(&[])) {
+            Err(ConfigError::UnknownProfile(name)) => assert_eq!(name, "staging"),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_rejects_unusable_rate_limits() {
+        for rate in &["-1.0", "nan", "1e-300"] {
+            let config = Config::from_toml(&format!(
+                "[profiles.dev]\ntoken = \"x\"\nrequests_per_second = {}",
+                rate
+            ))
+            .unwrap();
+            match config.profile_with_env(Some("dev"), env(&[])) {
+                Err(ConfigError::InvalidRateLimit { profile, .. }) => assert_eq!(profile, "dev"),
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn it_accepts_a_rate_limit_of_zero() {
+        let config =
+            Config::from_toml("[profiles.dev]\ntoken = \"x\"\nrequests_per_second = 0.0").unwrap();
+        let profile = config.profile_with_env(Some("dev"), env(&[])).unwrap();
+        assert_eq!(profile.requests_per_second, Some(0.0));
+    }
+
+    #[test]
+    fn it_rejects_unknown_settings() {
+        assert!(Config::from_toml("[profiles.dev]\ntokn = \"x\"").is_err());
+    }
+
+    #[test]
+    fn it_expands_home() {
+        assert!(!expand_home(Path::new("~/.cache")).starts_with("~"));
+        assert_eq!(
+            expand_home(Path::new("/var/cache")),
+            Path::new("/var/cache")
+        );
+    }
+}
@@ -0,0 +1,508 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::Difficulty;
+use crate::Entry;
+use crate::Lookup;
+use crate::RequestError;
+use crate::SearchQuery;
+use crate::Word;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Shown {
+    pub date: String,
+    pub word: String,
+}
+
+/// The words shown so far, oldest first.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct RotationState {
+    pub shown: Vec<Shown>,
+}
+
+impl RotationState {
+    /// Reads the state saved at `path`, or starts afresh if there is none.
+    pub fn load(path: &Path) -> io::Result<RotationState> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(RotationState::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json)
+    }
+
+    pub fn word_on(&self, date: &str) -> Option<&str> {
+        self.shown
+            .iter()
+            .find(|shown| shown.date == date)
+            .map(|shown| shown.word.as_str())
+    }
+
+    pub fn has_shown(&self, word: &str) -> bool {
+        self.shown.iter().any(|shown| shown.word == word)
+    }
+}
+
+/// Picks the word for each day, without repeating one.
+///
+/// A word list is shuffled once with a seed, so the rotation can be
+/// reproduced. Which word was shown on which date is kept in a
+/// `RotationState`, to be saved between runs.
+///
+/// ```no_run
+/// use std::path::Path;
+/// use wordsapi::daily::{DailyWords, RotationState};
+/// use wordsapi::{Client, Difficulty};
+///
+/// let client = Client::new("TOKEN");
+/// let daily = DailyWords::random(&client)
+///     .difficulty(Difficulty::Uncommon)
+///     .has_details("examples");
+/// let path = Path::new("rotation.json");
+/// let mut state = RotationState::load(path).unwrap();
+/// if let Some(word) = daily.word_for("2026-10-18", &mut state).unwrap() {
+///     println!("{}", word.to_markdown());
+/// }
+/// state.save(path).unwrap();
+/// ```
+pub struct DailyWords<'a, L: Lookup> {
+    lookup: &'a L,
+    words: Option<Vec<String>>,
+    difficulty: Option<Difficulty>,
+    has_details: Option<String>,
+    attempts: usize,
+}
+
+impl<'a, L: Lookup> DailyWords<'a, L> {
+    /// Draws from the API's random words.
+    pub fn random(lookup: &'a L) -> Self {
+        DailyWords {
+            lookup,
+            words: None,
+            difficulty: None,
+            has_details: None,
+            attempts: 10,
+        }
+    }
+
+    /// Goes through `words` in an order fixed by `seed`.
+    pub fn from_list(lookup: &'a L, words: &[&str], seed: u64) -> Self {
+        let mut words: Vec<String> = words.iter().map(|word| (*word).to_owned()).collect();
+        shuffle(&mut words, seed);
+        DailyWords {
+            words: Some(words),
+            ..DailyWords::random(lookup)
+        }
+    }
+
+    pub fn difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Only picks words with this detail, such as `examples`.
+    pub fn has_details(mut self, detail: &str) -> Self {
+        self.has_details = Some(detail.to_owned());
+        self
+    }
+
+    /// How many random words to draw before giving up on a day. Defaults to
+    /// 10.
+    pub fn attempts(mut self, attempts: usize) -> Self {
+        self.attempts = attempts;
+        self
+    }
+
+    /// The word for `date`, recorded in `state`. A date already in `state`
+    /// gets the same word again. `None` means no word that was not already
+    /// shown passed the filters.
+    pub fn word_for(
+        &self,
+        date: &str,
+        state: &mut RotationState,
+    ) -> Result<Option<DailyWord>, RequestError> {
+        if let Some(shown) = state.word_on(date) {
+            let word = self.lookup.look_up_parsed::<Word>(shown)?;
+            return Ok(Some(DailyWord::new(date, word)));
+        }
+        let picked = match self.words {
+            Some(ref words) => self.pick_from_list(words, state)?,
+            None => self.pick_random(state)?,
+        };
+        Ok(picked.map(|word| {
+            state.shown.push(Shown {
+                date: date.to_owned(),
+                word: word.word.clone(),
+            });
+            DailyWord::new(date, word)
+        }))
+    }
+
+    fn pick_from_list(
+        &self,
+        words: &[String],
+        state: &RotationState,
+    ) -> Result<Option<Word>, RequestError> {
+        for candidate in words.iter().filter(|word| !state.has_shown(word)) {
+            let word = match self.lookup.look_up_parsed::<Word>(candidate) {
+                Ok(word) => word,
+                Err(RequestError::NotFound { .. }) => continue,
+                Err(e) => return Err(e),
+            };
+            if self.accepts(&word) {
+                return Ok(Some(word));
+            }
+        }
+        Ok(None)
+    }
+
+    fn pick_random(&self, state: &RotationState) -> Result<Option<Word>, RequestError> {
+        let mut query = SearchQuery::new();
+        if let Some(difficulty) = self.difficulty {
+            let (min, max) = difficulty.zipf_range();
+            query.frequency_min = min;
+            query.frequency_max = max;
+        }
+        query.has_details = self.has_details.clone();
+        for _ in 0..self.attempts {
+            let word = self.lookup.random_matching_parsed(&query)?;
+            if !state.has_shown(&word.word) && self.accepts(&word) {
+                return Ok(Some(word));
+            }
+        }
+        Ok(None)
+    }
+
+    fn accepts(&self, word: &Word) -> bool {
+        let difficulty = word
+            .frequency
+            .map_or(Difficulty::Rare, Difficulty::from_zipf);
+        self.difficulty.is_none_or(|wanted| wanted == difficulty)
+            && !word.entries.is_empty()
+            && self
+                .has_details
+                .as_ref()
+                .is_none_or(|detail| has_detail(word, detail))
+    }
+}
+
+/// Whether the API's `hasDetails` filter would match `word`: the word or one
+/// of its entries has the field, and it is not empty.
+fn has_detail(word: &Word, detail: &str) -> bool {
+    if detail == "definitions" {
+        return !word.entries.is_empty();
+    }
+    let present = |value: Option<&Value>| match value {
+        None | Some(Value::Null) => false,
+        Some(Value::Array(items)) => !items.is_empty(),
+        Some(_) => true,
+    };
+    let word = match serde_json::to_value(word) {
+        Ok(word) => word,
+        Err(_) => return false,
+    };
+    present(word.get(detail))
+        || word["results"]
+            .as_array()
+            .is_some_and(|entries| entries.iter().any(|entry| present(entry.get(detail))))
+}
+
+/// Fisher-Yates with SplitMix64, so the order depends only on the seed.
+fn shuffle<T>(items: &mut [T], seed: u64) {
+    let mut state = seed;
+    let mut next = || {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    };
+    for i in (1..items.len()).rev() {
+        let j = (next() % (i as u64 + 1)) as usize;
+        items.swap(i, j);
+    }
+}
+
+/// A day's word with the sense to show.
+#[derive(Debug)]
+pub struct DailyWord {
+    pub date: String,
+    pub word: Word,
+    /// The first entry with an example, or the first entry.
+    pub entry: usize,
+    pub example: Option<String>,
+}
+
+impl DailyWord {
+    fn new(date: &str, word: Word) -> DailyWord {
+        let entry = word
+            .entries
+            .iter()
+            .position(|entry| first_example(entry).is_some())
+            .unwrap_or(0);
+        let example = word
+            .entries
+            .get(entry)
+            .and_then(first_example)
+            .map(str::to_owned);
+        DailyWord {
+            date: date.to_owned(),
+            word,
+            entry,
+            example,
+        }
+    }
+
+    pub fn definition(&self) -> &str {
+        self.word
+            .entries
+            .get(self.entry)
+            .map_or("", |entry| entry.definition.as_str())
+    }
+
+    pub fn part_of_speech(&self) -> Option<&str> {
+        self.word
+            .entries
+            .get(self.entry)
+            .and_then(|entry| entry.part_of_speech.as_deref())
+    }
+
+    fn pronunciation(&self) -> Option<&str> {
+        self.word.pronunciation_of(self.part_of_speech())
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("**{}**", escape_markdown(&self.word.word));
+        if let Some(pronunciation) = self.pronunciation() {
+            out.push_str(&format!(" /{}/", escape_markdown(pronunciation)));
+        }
+        if let Some(part_of_speech) = self.part_of_speech() {
+            out.push_str(&format!(" *{}*", part_of_speech));
+        }
+        out.push_str(&format!("\n\n{}\n", escape_markdown(self.definition())));
+        if let Some(ref example) = self.example {
+            out.push_str(&format!("\n> {}\n", escape_markdown(example)));
+        }
+        out
+    }
+
+    pub fn to_html(&self) -> String {
+        let mut out = format!(
+            "<div class=\"word-of-the-day\">\n<h2>{}</h2>\n",
+            escape_html(&self.word.word)
+        );
+        if let Some(pronunciation) = self.pronunciation() {
+            out.push_str(&format!(
+                "<p class=\"pronunciation\">/{}/</p>\n",
+                escape_html(pronunciation)
+            ));
+        }
+        if let Some(part_of_speech) = self.part_of_speech() {
+            out.push_str(&format!(
+                "<p class=\"part-of-speech\"><em>{}</em></p>\n",
+                escape_html(part_of_speech)
+            ));
+        }
+        out.push_str(&format!("<p>{}</p>\n", escape_html(self.definition())));
+        if let Some(ref example) = self.example {
+            out.push_str(&format!(
+                "<blockquote>{}</blockquote>\n",
+                escape_html(example)
+            ));
+        }
+        out.push_str("</div>\n");
+        out
+    }
+}
+
+/// Plain text.
+impl fmt::Display for DailyWord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.word.word)?;
+        if let Some(pronunciation) = self.pronunciation() {
+            write!(f, " /{}/", pronunciation)?;
+        }
+        if let Some(part_of_speech) = self.part_of_speech() {
+            write!(f, " ({})", part_of_speech)?;
+        }
+        writeln!(f, "\n{}", self.definition())?;
+        if let Some(ref example) = self.example {
+            writeln!(f, "\"{}\"", example)?;
+        }
+        Ok(())
+    }
+}
+
+fn first_example(entry: &Entry) -> Option<&str> {
+    entry
+        .examples
+        .as_ref()
+        .and_then(|examples| examples.first())
+        .map(String::as_str)
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use crate::daily::{shuffle, DailyWords, RotationState};
+    use crate::lookup::fake::FakeLookup;
+    use crate::Difficulty;
+
+    fn word(name: &str, frequency: f32, example: bool) -> String {
+        let examples = if example {
+            format!(r#", "examples": ["a {} example"]"#, name)
+        } else {
+            String::new()
+        };
+        format!(
+            r#"{{"word": "{}", "frequency": {}, "results": [
+                {{"definition": "first sense of {}", "partOfSpeech": "noun"}},
+                {{"definition": "second sense of {}", "partOfSpeech": "verb"{}}}
+            ]}}"#,
+            name, frequency, name, name, examples
+        )
+    }
+
+    fn lookup() -> FakeLookup {
+        ["alpha", "bravo", "charlie", "delta", "echo"]
+            .iter()
+            .fold(FakeLookup::new(), |lookup, name| {
+                lookup.word(&word(name, 3.0, *name != "charlie"))
+            })
+            .word(&word("foxtrot", 5.0, true))
+    }
+
+    static LIST: &[&str] = &[
+        "alpha", "bravo", "charlie", "delta", "echo", "foxtrot", "golf",
+    ];
+
+    #[test]
+    fn it_shuffles_reproducibly() {
+        let mut a: Vec<usize> = (0..20).collect();
+        let mut b = a.clone();
+        shuffle(&mut a, 7);
+        shuffle(&mut b, 7);
+        assert_eq!(a, b);
+        assert_ne!(a, (0..20).collect::<Vec<usize>>());
+        shuffle(&mut b, 8);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn it_rotates_through_a_list_without_repeats() {
+        let lookup = lookup();
+        let daily = DailyWords::from_list(&lookup, LIST, 42)
+            .difficulty(Difficulty::Uncommon)
+            .has_details("examples");
+        let mut state = RotationState::default();
+        let mut shown = Vec::new();
+        for day in 1..=5 {
+            let date = format!("2026-10-{:02}", day);
+            match daily.word_for(&date, &mut state).unwrap() {
+                Some(word) => shown.push(word.word.word),
+                None => break,
+            }
+        }
+        shown.sort();
+        assert_eq!(shown, vec!["alpha", "bravo", "delta", "echo"]);
+        assert_eq!(state.shown.len(), 4);
+
+        let again = daily.word_for("2026-10-02", &mut state).unwrap().unwrap();
+        assert_eq!(again.word.word, state.shown[1].word);
+        assert_eq!(state.shown.len(), 4);
+    }
+
+    #[test]
+    fn it_draws_random_words_with_filters() {
+        let lookup = FakeLookup::new()
+            .random_word(&word("alpha", 3.0, true))
+            .random_word(&word("foxtrot", 5.0, true))
+            .random_word(&word("bravo", 3.0, true));
+        let mut state = RotationState::default();
+        state.shown.push(crate::daily::Shown {
+            date: "2026-10-17".to_owned(),
+            word: "alpha".to_owned(),
+        });
+        let daily = DailyWords::random(&lookup)
+            .difficulty(Difficulty::Uncommon)
+            .has_details("examples");
+        let today = daily.word_for("2026-10-18", &mut state).unwrap().unwrap();
+        assert_eq!(today.word.word, "bravo");
+        assert_eq!(
+            lookup.queries.borrow()[0].query_string(),
+            "frequencyMin=2.5&frequencyMax=4&hasDetails=examples"
+        );
+    }
+
+    #[test]
+    fn it_renders_snippets() {
+        let lookup = lookup();
+        let mut state = RotationState::default();
+        let today = DailyWords::from_list(&lookup, &["alpha"], 1)
+            .word_for("2026-10-18", &mut state)
+            .unwrap()
+            .unwrap();
+        assert_eq!(today.entry, 1);
+        assert_eq!(
+            today.to_markdown(),
+            "**alpha** *verb*\n\nsecond sense of alpha\n\n> a alpha example\n"
+        );
+        assert_eq!(
+            today.to_html(),
+            "<div class=\"word-of-the-day\">\n<h2>alpha</h2>\n\
+             <p class=\"part-of-speech\"><em>verb</em></p>\n\
+             <p>second sense of alpha</p>\n<blockquote>a alpha example</blockquote>\n</div>\n"
+        );
+        assert_eq!(
+            today.to_string(),
+            "alpha (verb)\nsecond sense of alpha\n\"a alpha example\"\n"
+        );
+    }
+
+    #[test]
+    fn it_saves_and_loads_state() {
+        let path = env::temp_dir().join(format!("wordsapi-daily-{}.json", std::process::id()));
+        assert_eq!(
+            RotationState::load(&path).unwrap(),
+            RotationState::default()
+        );
+        let lookup = lookup();
+        let mut state = RotationState::default();
+        DailyWords::from_list(&lookup, LIST, 3)
+            .word_for("2026-10-18", &mut state)
+            .unwrap();
+        state.save(&path).unwrap();
+        let loaded = RotationState::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, state);
+    }
+}
@@ -0,0 +1,500 @@
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::graph::Relation;
+use crate::try_parse;
+use crate::Entry;
+use crate::Syllables;
+use crate::Word;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Frequency {
+        old: Option<f32>,
+        new: Option<f32>,
+    },
+    Pronunciation {
+        old: Option<HashMap<String, String>>,
+        new: Option<HashMap<String, String>>,
+    },
+    Syllables {
+        old: Option<Syllables>,
+        new: Option<Syllables>,
+    },
+    /// `index` is the entry's position in the new snapshot.
+    EntryAdded {
+        index: usize,
+        definition: String,
+    },
+    /// `index` is the entry's position in the old snapshot.
+    EntryRemoved {
+        index: usize,
+        definition: String,
+    },
+    DefinitionChanged {
+        old_index: usize,
+        new_index: usize,
+        old: String,
+        new: String,
+    },
+    /// A list field of a matched entry, such as `typeOf` or `examples`.
+    ListChanged {
+        old_index: usize,
+        new_index: usize,
+        field: &'static str,
+        added: Vec<String>,
+        removed: Vec<String>,
+    },
+    /// A field this crate does not model, kept in `extra`. `new_index` is the
+    /// matched entry's position in the new snapshot, or `None` for a field
+    /// of the word itself.
+    ExtraChanged {
+        new_index: Option<usize>,
+        field: String,
+        old: Option<Value>,
+        new: Option<Value>,
+    },
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Change::Frequency { old, new } => write!(f, "frequency: {:?} -> {:?}", old, new),
+            Change::Pronunciation { ref old, ref new } => {
+                write!(f, "pronunciation: {:?} -> {:?}", old, new)
+            }
+            Change::Syllables { ref old, ref new } => write!(
+                f,
+                "syllables: {:?} -> {:?}",
+                old.as_ref().map(|s| s.list.join("-")),
+                new.as_ref().map(|s| s.list.join("-"))
+            ),
+            Change::EntryAdded {
+                index,
+                ref definition,
+            } => {
+                write!(f, "+ entry {}: {}", index, definition)
+            }
+            Change::EntryRemoved {
+                index,
+                ref definition,
+            } => {
+                write!(f, "- entry {}: {}", index, definition)
+            }
+            Change::DefinitionChanged {
+                new_index,
+                ref old,
+                ref new,
+                ..
+            } => write!(f, "~ entry {}: {:?} -> {:?}", new_index, old, new),
+            Change::ListChanged {
+                new_index,
+                field,
+                ref added,
+                ref removed,
+                ..
+            } => {
+                write!(f, "~ entry {} {}:", new_index, field)?;
+                for term in added {
+                    write!(f, " +{}", term)?;
+                }
+                for term in removed {
+                    write!(f, " -{}", term)?;
+                }
+                Ok(())
+            }
+            Change::ExtraChanged {
+                new_index,
+                ref field,
+                ref old,
+                ref new,
+            } => {
+                let show = |value: &Option<Value>| {
+                    value
+                        .as_ref()
+                        .map_or_else(|| "none".to_owned(), Value::to_string)
+                };
+                match new_index {
+                    Some(index) => write!(f, "~ entry {} {}: ", index, field)?,
+                    None => write!(f, "{}: ", field)?,
+                }
+                write!(f, "{} -> {}", show(old), show(new))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WordDiff {
+    pub word: String,
+    pub changes: Vec<Change>,
+}
+
+impl WordDiff {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl fmt::Display for WordDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.word)?;
+        for change in &self.changes {
+            writeln!(f, "  {}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// The changes from the `old` snapshot of a word to the `new` one.
+///
+/// Entries have no identifier, so they are matched by part of speech and
+/// definition. Entries left over on both sides are then paired in order
+/// within a part of speech, as one entry whose definition was reworded;
+/// anything still left over was added or removed.
+pub fn diff(old: &Word, new: &Word) -> WordDiff {
+    let mut changes = Vec::new();
+    if old.frequency != new.frequency {
+        changes.push(Change::Frequency {
+            old: old.frequency,
+            new: new.frequency,
+        });
+    }
+    if old.pronunciation != new.pronunciation {
+        changes.push(Change::Pronunciation {
+            old: old.pronunciation.clone(),
+            new: new.pronunciation.clone(),
+        });
+    }
+    if old.syllables != new.syllables {
+        changes.push(Change::Syllables {
+            old: old.syllables.clone(),
+            new: new.syllables.clone(),
+        });
+    }
+    extra_changes(&old.extra, &new.extra, None, &mut changes);
+
+    let (pairs, removed, added) = match_entries(&old.entries, &new.entries);
+    for index in removed {
+        changes.push(Change::EntryRemoved {
+            index,
+            definition: old.entries[index].definition.clone(),
+        });
+    }
+    for (old_index, new_index) in pairs {
+        let (old_entry, new_entry) = (&old.entries[old_index], &new.entries[new_index]);
+        if old_entry.definition != new_entry.definition {
+            changes.push(Change::DefinitionChanged {
+                old_index,
+                new_index,
+                old: old_entry.definition.clone(),
+                new: new_entry.definition.clone(),
+            });
+        }
+        let lists = Relation::ALL
+            .iter()
+            .map(|relation| {
+                (
+                    relation.field_name(),
+                    relation.targets(old_entry),
+                    relation.targets(new_entry),
+                )
+            })
+            .chain(Some((
+                "examples",
+                old_entry.examples.as_deref().unwrap_or(&[]),
+                new_entry.examples.as_deref().unwrap_or(&[]),
+            )));
+        for (field, old_list, new_list) in lists {
+            let added = missing_from(new_list, old_list);
+            let removed = missing_from(old_list, new_list);
+            if !added.is_empty() || !removed.is_empty() {
+                changes.push(Change::ListChanged {
+                    old_index,
+                    new_index,
+                    field,
+                    added,
+                    removed,
+                });
+            }
+        }
+        extra_changes(
+            &old_entry.extra,
+            &new_entry.extra,
+            Some(new_index),
+            &mut changes,
+        );
+    }
+    for index in added {
+        changes.push(Change::EntryAdded {
+            index,
+            definition: new.entries[index].definition.clone(),
+        });
+    }
+    WordDiff {
+        word: new.word.clone(),
+        changes,
+    }
+}
+
+/// Pairs of (old, new) entry indices, then the unmatched old and new indices.
+type Matching = (Vec<(usize, usize)>, Vec<usize>, Vec<usize>);
+
+fn match_entries(old: &[Entry], new: &[Entry]) -> Matching {
+    let mut pairs = Vec::new();
+    let mut old_left: Vec<usize> = (0..old.len()).collect();
+    let mut new_left: Vec<usize> = (0..new.len()).collect();
+    let same_definition =
+        |a: &Entry, b: &Entry| a.part_of_speech == b.part_of_speech && a.definition == b.definition;
+    let same_part_of_speech = |a: &Entry, b: &Entry| a.part_of_speech == b.part_of_speech;
+    for matches in [same_definition, same_part_of_speech].iter() {
+        old_left.retain(
+            |&o| match new_left.iter().position(|&n| matches(&old[o], &new[n])) {
+                Some(position) => {
+                    pairs.push((o, new_left.remove(position)));
+                    false
+                }
+                None => true,
+            },
+        );
+    }
+    pairs.sort_by_key(|&(_, n)| n);
+    (pairs, old_left, new_left)
+}
+
+/// Changes to the unmodelled fields of a word or entry, by field name.
+fn extra_changes(
+    old: &Map<String, Value>,
+    new: &Map<String, Value>,
+    new_index: Option<usize>,
+    changes: &mut Vec<Change>,
+) {
+    let fields: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for field in fields {
+        let (old_value, new_value) = (old.get(field), new.get(field));
+        if old_value != new_value {
+            changes.push(Change::ExtraChanged {
+                new_index,
+                field: field.clone(),
+                old: old_value.cloned(),
+                new: new_value.cloned(),
+            });
+        }
+    }
+}
+
+fn missing_from(list: &[String], other: &[String]) -> Vec<String> {
+    let other: HashSet<&String> = other.iter().collect();
+    list.iter()
+        .filter(|term| !other.contains(term))
+        .cloned()
+        .collect()
+}
+
+/// The result of comparing two archive directories.
+#[derive(Debug, Default)]
+pub struct ArchiveDiff {
+    /// Words present in both archives that changed, with their file name.
+    pub changed: Vec<(OsString, WordDiff)>,
+    /// File names only in the new archive.
+    pub added: Vec<OsString>,
+    /// File names only in the old archive.
+    pub removed: Vec<OsString>,
+    /// Files that could not be read or parsed as a `Word`.
+    pub unreadable: Vec<PathBuf>,
+}
+
+/// Compares two directories of archived `response_json` files, one word per
+/// `.json` file. Files are matched by name; other files are ignored.
+pub fn diff_archives(old: &Path, new: &Path) -> io::Result<ArchiveDiff> {
+    let old_files = json_files(old)?;
+    let new_files = json_files(new)?;
+    let mut result = ArchiveDiff::default();
+    for name in old_files.keys() {
+        if !new_files.contains_key(name) {
+            result.removed.push(name.clone());
+        }
+    }
+    for (name, new_path) in &new_files {
+        let old_path = match old_files.get(name) {
+            Some(old_path) => old_path,
+            None => {
+                result.added.push(name.clone());
+                continue;
+            }
+        };
+        let old_word = read_word(old_path);
+        let new_word = read_word(new_path);
+        match (old_word, new_word) {
+            (Some(old_word), Some(new_word)) => {
+                let word_diff = diff(&old_word, &new_word);
+                if !word_diff.is_empty() {
+                    result.changed.push((name.clone(), word_diff));
+                }
+            }
+            (old_word, new_word) => {
+                if old_word.is_none() {
+                    result.unreadable.push(old_path.clone());
+                }
+                if new_word.is_none() {
+                    result.unreadable.push(new_path.clone());
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn json_files(dir: &Path) -> io::Result<BTreeMap<OsString, PathBuf>> {
+    let mut files = BTreeMap::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_file() && path.extension().is_some_and(|ext| ext == "json") {
+            if let Some(name) = path.file_name() {
+                files.insert(name.to_owned(), path.clone());
+            }
+        }
+    }
+    Ok(files)
+}
+
+fn read_word(path: &Path) -> Option<Word> {
+    let json = fs::read_to_string(path).ok()?;
+    try_parse::<Word>(&json).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+
+    use serde_json::json;
+
+    use crate::diff::{diff, diff_archives, Change};
+    use crate::try_parse;
+    use crate::Word;
+
+    static OLD: &str = r#"{"word": "bank", "frequency": 4.8, "results": [
+        {"definition": "a financial institution", "partOfSpeech": "noun", "typeOf": ["institution"]},
+        {"definition": "sloping land beside water", "partOfSpeech": "noun"},
+        {"definition": "tip laterally", "partOfSpeech": "verb"}
+    ]}"#;
+
+    static NEW: &str = r#"{"word": "bank", "frequency": 4.8, "results": [
+        {"definition": "a financial institution", "partOfSpeech": "noun",
+         "typeOf": ["financial institution"], "synonyms": ["depository"]},
+        {"definition": "sloping land beside a body of water", "partOfSpeech": "noun"},
+        {"definition": "a long ridge or pile", "partOfSpeech": "noun"}
+    ]}"#;
+
+    #[test]
+    fn it_reports_entry_changes() {
+        let old = try_parse::<Word>(OLD).unwrap();
+        let new = try_parse::<Word>(NEW).unwrap();
+        let changes = diff(&old, &new).changes;
+        assert_eq!(
+            changes,
+            vec![
+                Change::EntryRemoved {
+                    index: 2,
+                    definition: "tip laterally".to_owned(),
+                },
+                Change::ListChanged {
+                    old_index: 0,
+                    new_index: 0,
+                    field: "typeOf",
+                    added: vec!["financial institution".to_owned()],
+                    removed: vec!["institution".to_owned()],
+                },
+                Change::ListChanged {
+                    old_index: 0,
+                    new_index: 0,
+                    field: "synonyms",
+                    added: vec!["depository".to_owned()],
+                    removed: vec![],
+                },
+                Change::DefinitionChanged {
+                    old_index: 1,
+                    new_index: 1,
+                    old: "sloping land beside water".to_owned(),
+                    new: "sloping land beside a body of water".to_owned(),
+                },
+                Change::EntryAdded {
+                    index: 2,
+                    definition: "a long ridge or pile".to_owned(),
+                },
+            ]
+        );
+        assert_eq!(
+            changes[1].to_string(),
+            "~ entry 0 typeOf: +financial institution -institution"
+        );
+    }
+
+    #[test]
+    fn it_reports_changes_to_unmodelled_fields() {
+        let old = try_parse::<Word>(
+            r#"{"word": "bank", "letters": 4, "results": [
+                {"definition": "a financial institution", "regionOf": ["x"]}
+            ]}"#,
+        )
+        .unwrap();
+        let new = try_parse::<Word>(
+            r#"{"word": "bank", "letters": 4, "results": [
+                {"definition": "a financial institution", "regionOf": ["y"]}
+            ]}"#,
+        )
+        .unwrap();
+        let changes = diff(&old, &new).changes;
+        assert_eq!(
+            changes,
+            vec![Change::ExtraChanged {
+                new_index: Some(0),
+                field: "regionOf".to_owned(),
+                old: Some(json!(["x"])),
+                new: Some(json!(["y"])),
+            }]
+        );
+        assert_eq!(
+            changes[0].to_string(),
+            r#"~ entry 0 regionOf: ["x"] -> ["y"]"#
+        );
+    }
+
+    #[test]
+    fn it_finds_no_changes_in_identical_snapshots() {
+        let word = try_parse::<Word>(OLD).unwrap();
+        assert!(diff(&word, &try_parse::<Word>(OLD).unwrap()).is_empty());
+    }
+
+    #[test]
+    fn it_compares_archive_directories() {
+        let root = env::temp_dir().join(format!("wordsapi-diff-{}", std::process::id()));
+        let (old, new) = (root.join("old"), root.join("new"));
+        fs::create_dir_all(&old).unwrap();
+        fs::create_dir_all(&new).unwrap();
+        fs::write(old.join("bank.json"), OLD).unwrap();
+        fs::write(new.join("bank.json"), NEW).unwrap();
+        fs::write(old.join("gone.json"), OLD).unwrap();
+        fs::write(new.join("fresh.json"), NEW).unwrap();
+        fs::write(old.join("same.json"), OLD).unwrap();
+        fs::write(new.join("same.json"), OLD).unwrap();
+        fs::write(old.join("broken.json"), OLD).unwrap();
+        fs::write(new.join("broken.json"), "{").unwrap();
+        fs::write(new.join("notes.txt"), "ignored").unwrap();
+
+        let result = diff_archives(&old, &new).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(result.changed.len(), 1);
+        assert_eq!(result.changed[0].0, "bank.json");
+        assert_eq!(result.changed[0].1.word, "bank");
+        assert_eq!(result.added, vec!["fresh.json"]);
+        assert_eq!(result.removed, vec!["gone.json"]);
+        assert_eq!(result.unreadable, vec![new.join("broken.json")]);
+    }
+}
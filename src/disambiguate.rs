@@ -0,0 +1,170 @@
+use std::collections::{BTreeSet, HashMap};
+
+use crate::Entry;
+use crate::Word;
+
+static STOPWORDS: &[&str] = &[
+    "a", "about", "after", "all", "also", "an", "and", "any", "are", "as", "at", "be", "been",
+    "but", "by", "can", "could", "did", "do", "does", "for", "from", "had", "has", "have", "he",
+    "her", "his", "how", "i", "if", "in", "into", "is", "it", "its", "may", "me", "more", "most",
+    "my", "no", "not", "of", "on", "one", "or", "other", "our", "out", "she", "so", "some", "such",
+    "than", "that", "the", "their", "them", "then", "there", "these", "they", "this", "to", "up",
+    "us", "used", "usually", "very", "was", "we", "were", "what", "when", "which", "who", "will",
+    "with", "would", "you", "your",
+];
+
+/// Overlap with synonyms and hypernyms says more about a sense than overlap
+/// with the words of a definition, so it counts double.
+const DEFINITION_WEIGHT: f64 = 1.0;
+const RELATION_WEIGHT: f64 = 2.0;
+
+/// An entry of a word and how well it fits the context.
+#[derive(Debug, Clone)]
+pub struct RankedSense<'a> {
+    /// The position of the entry in `Word::entries`.
+    pub index: usize,
+    pub entry: &'a Entry,
+    pub score: f64,
+    /// The context words found in the entry.
+    pub overlap: BTreeSet<String>,
+}
+
+/// Ranks the entries of `word` by how well they fit `context`, best first.
+///
+/// This is the simplified Lesk algorithm: each entry is scored by the words
+/// it shares with the context, looking at its definition and examples, and,
+/// with double weight, its synonyms and `typeOf` terms. Entries that score
+/// the same keep the API's order.
+pub fn disambiguate<'a>(word: &'a Word, context: &str) -> Vec<RankedSense<'a>> {
+    let own_word = content_words(&word.word);
+    let context: BTreeSet<String> = content_words(context)
+        .into_iter()
+        .filter(|token| !own_word.contains(token))
+        .collect();
+    let mut ranked: Vec<RankedSense> = word
+        .entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let signature = signature(entry);
+            let mut score = 0.0;
+            let mut overlap = BTreeSet::new();
+            for token in &context {
+                if let Some(weight) = signature.get(token) {
+                    score += weight;
+                    overlap.insert(token.clone());
+                }
+            }
+            RankedSense {
+                index,
+                entry,
+                score,
+                overlap,
+            }
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(a.index.cmp(&b.index))
+    });
+    ranked
+}
+
+/// The words describing `entry`, each with the weight of its strongest source.
+fn signature(entry: &Entry) -> HashMap<String, f64> {
+    let mut signature = HashMap::new();
+    let mut add = |text: &str, weight: f64| {
+        for token in content_words(text) {
+            let current = signature.entry(token).or_insert(weight);
+            if *current < weight {
+                *current = weight;
+            }
+        }
+    };
+    add(&entry.definition, DEFINITION_WEIGHT);
+    for example in entry.examples.iter().flatten() {
+        add(example, DEFINITION_WEIGHT);
+    }
+    for term in entry.synonyms.iter().chain(entry.type_of.iter()).flatten() {
+        add(term, RELATION_WEIGHT);
+    }
+    signature
+}
+
+/// Lowercased words of `text` without stopwords, with plural endings removed.
+fn content_words(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .filter(|token| !STOPWORDS.contains(&token.as_str()))
+        .map(|token| singular(&token))
+        .collect()
+}
+
+fn singular(token: &str) -> String {
+    if token.len() > 4 && token.ends_with("ies") {
+        format!("{}y", &token[..token.len() - 3])
+    } else if token.len() > 3 && token.ends_with('s') && !token.ends_with("ss") {
+        token[..token.len() - 1].to_owned()
+    } else {
+        token.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::disambiguate::{content_words, disambiguate};
+    use crate::try_parse;
+    use crate::Word;
+
+    fn bank() -> Word {
+        try_parse::<Word>(
+            r#"{"word": "bank", "results": [
+                {"definition": "a financial institution that accepts deposits and channels the money into lending activities",
+                 "partOfSpeech": "noun", "typeOf": ["financial institution"], "synonyms": ["depository financial institution"],
+                 "examples": ["he cashed a check at the bank"]},
+                {"definition": "sloping land beside a body of water",
+                 "partOfSpeech": "noun", "typeOf": ["slope", "incline"],
+                 "examples": ["they pulled the canoe up on the bank"]},
+                {"definition": "a long ridge or pile", "partOfSpeech": "noun"}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_picks_the_sense_matching_the_context() {
+        let word = bank();
+        let ranked = disambiguate(
+            &word,
+            "We paddled the canoe to the river bank and sat on the grassy slope.",
+        );
+        assert_eq!(ranked[0].index, 1);
+        assert!(ranked[0].overlap.contains("canoe"));
+        assert!(ranked[0].overlap.contains("slope"));
+        let ranked = disambiguate(
+            &word,
+            "The bank raised interest on deposits held by financial institutions.",
+        );
+        assert_eq!(ranked[0].index, 0);
+        assert!(ranked[0].score > ranked[1].score);
+    }
+
+    #[test]
+    fn it_keeps_api_order_without_overlap() {
+        let word = bank();
+        let ranked = disambiguate(&word, "nothing relevant here");
+        let order: Vec<usize> = ranked.iter().map(|sense| sense.index).collect();
+        assert_eq!(order, vec![0, 1, 2]);
+        assert!(ranked.iter().all(|sense| sense.score == 0.0));
+    }
+
+    #[test]
+    fn it_ignores_stopwords_and_plurals() {
+        let words = content_words("The banks of the rivers, and the Cities.");
+        let words: Vec<&str> = words.iter().map(String::as_str).collect();
+        assert_eq!(words, vec!["bank", "city", "river"]);
+    }
+}
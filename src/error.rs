@@ -1,10 +1,13 @@
 use core::fmt;
 use std::error::Error as StdError;
+use std::io;
 
 #[derive(Debug)]
 pub enum RequestError {
     RequestError,
     ResultParseError,
+    NotFound { word: String },
+    InvalidWord { word: String, reason: &'static str },
 }
 
 impl fmt::Display for RequestError {
@@ -12,6 +15,10 @@ impl fmt::Display for RequestError {
         match *self {
             RequestError::RequestError => f.write_str("RequestError"),
             RequestError::ResultParseError => f.write_str("ResultParseError"),
+            RequestError::NotFound { ref word } => write!(f, "NotFound: {}", word),
+            RequestError::InvalidWord { ref word, reason } => {
+                write!(f, "InvalidWord: {:?} {}", word, reason)
+            }
         }
     }
 }
@@ -21,6 +28,57 @@ impl StdError for RequestError {
         match *self {
             RequestError::RequestError => "WordAPI request failed",
             RequestError::ResultParseError => "Could not parse result",
+            RequestError::NotFound { .. } => "Word not found",
+            RequestError::InvalidWord { .. } => "Word is not a valid request",
         }
     }
 }
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(String),
+    UnknownProfile(String),
+    MissingToken(String),
+    InvalidRateLimit {
+        profile: String,
+        requests_per_second: f64,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ConfigError::Io(ref e) => write!(f, "Io: {}", e),
+            ConfigError::Parse(ref e) => write!(f, "Parse: {}", e),
+            ConfigError::UnknownProfile(ref name) => write!(f, "UnknownProfile: {}", name),
+            ConfigError::MissingToken(ref name) => write!(f, "MissingToken: {}", name),
+            ConfigError::InvalidRateLimit {
+                ref profile,
+                requests_per_second,
+            } => write!(
+                f,
+                "InvalidRateLimit: {} in {}",
+                requests_per_second, profile
+            ),
+        }
+    }
+}
+
+impl StdError for ConfigError {
+    fn description(&self) -> &str {
+        match *self {
+            ConfigError::Io(_) => "Could not read configuration",
+            ConfigError::Parse(_) => "Could not parse configuration",
+            ConfigError::UnknownProfile(_) => "No such profile",
+            ConfigError::MissingToken(_) => "Profile has no token",
+            ConfigError::InvalidRateLimit { .. } => "Profile has an unusable rate limit",
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
@@ -0,0 +1,270 @@
+use std::io::{self, Write};
+
+use crate::Entry;
+use crate::Word;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Csv,
+    Tsv,
+    JsonLines,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Word,
+    /// The position of the entry in `Word::entries`.
+    Sense,
+    PartOfSpeech,
+    Definition,
+    Synonyms,
+    TypeOf,
+    Examples,
+    Frequency,
+}
+
+impl Column {
+    pub const ALL: [Column; 8] = [
+        Column::Word,
+        Column::Sense,
+        Column::PartOfSpeech,
+        Column::Definition,
+        Column::Synonyms,
+        Column::TypeOf,
+        Column::Examples,
+        Column::Frequency,
+    ];
+
+    /// The column's header.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Column::Word => "word",
+            Column::Sense => "sense",
+            Column::PartOfSpeech => "partOfSpeech",
+            Column::Definition => "definition",
+            Column::Synonyms => "synonyms",
+            Column::TypeOf => "typeOf",
+            Column::Examples => "examples",
+            Column::Frequency => "frequency",
+        }
+    }
+
+    /// The column's value for one entry of `word`, or for a word with no
+    /// entries, whose entry columns are empty.
+    fn value(&self, word: &Word, entry: Option<(usize, &Entry)>, delimiter: &str) -> String {
+        let join = |list: &Option<Vec<String>>| {
+            list.as_ref()
+                .map(|list| list.join(delimiter))
+                .unwrap_or_default()
+        };
+        match (*self, entry) {
+            (Column::Word, _) => word.word.clone(),
+            (Column::Frequency, _) => word
+                .frequency
+                .map(|frequency| frequency.to_string())
+                .unwrap_or_default(),
+            (_, None) => String::new(),
+            (Column::Sense, Some((index, _))) => index.to_string(),
+            (Column::PartOfSpeech, Some((_, entry))) => {
+                entry.part_of_speech.clone().unwrap_or_default()
+            }
+            (Column::Definition, Some((_, entry))) => entry.definition.clone(),
+            (Column::Synonyms, Some((_, entry))) => join(&entry.synonyms),
+            (Column::TypeOf, Some((_, entry))) => join(&entry.type_of),
+            (Column::Examples, Some((_, entry))) => join(&entry.examples),
+        }
+    }
+}
+
+/// Streams words to `out` as they are written, so an export never holds
+/// more than one word in memory.
+///
+/// CSV and TSV have a header and one row per `Entry`, with lists joined into
+/// one field. A word without entries gets one row with empty entry columns.
+/// JSON Lines has one complete `Word` per line.
+///
+/// ```
+/// use wordsapi::export::{Column, Exporter, Format};
+///
+/// let mut exporter = Exporter::new(Vec::new(), Format::Tsv)
+///     .columns(&[Column::Word, Column::Synonyms])
+///     .list_delimiter("|");
+/// // for word in words { exporter.write_word(&word)?; }
+/// let tsv = exporter.finish().unwrap();
+/// assert_eq!(tsv, b"word\tsynonyms\n");
+/// ```
+pub struct Exporter<W: Write> {
+    out: W,
+    format: Format,
+    columns: Vec<Column>,
+    list_delimiter: String,
+    header_written: bool,
+}
+
+impl<W: Write> Exporter<W> {
+    /// An exporter writing every column, with lists joined by `; `.
+    pub fn new(out: W, format: Format) -> Self {
+        Exporter {
+            out,
+            format,
+            columns: Column::ALL.to_vec(),
+            list_delimiter: "; ".to_owned(),
+            header_written: false,
+        }
+    }
+
+    /// The columns to write, in order. JSON Lines always has every field.
+    pub fn columns(mut self, columns: &[Column]) -> Self {
+        self.columns = columns.to_vec();
+        self
+    }
+
+    pub fn list_delimiter(mut self, delimiter: &str) -> Self {
+        self.list_delimiter = delimiter.to_owned();
+        self
+    }
+
+    pub fn write_word(&mut self, word: &Word) -> io::Result<()> {
+        if self.format == Format::JsonLines {
+            serde_json::to_writer(&mut self.out, word)?;
+            return writeln!(self.out);
+        }
+        self.write_header()?;
+        let entries: Vec<Option<(usize, &Entry)>> = if word.entries.is_empty() {
+            vec![None]
+        } else {
+            word.entries.iter().enumerate().map(Some).collect()
+        };
+        for entry in entries {
+            let row: Vec<String> = self
+                .columns
+                .iter()
+                .map(|column| column.value(word, entry, &self.list_delimiter))
+                .collect();
+            self.write_row(&row)?;
+        }
+        Ok(())
+    }
+
+    pub fn write_words<'w, I: IntoIterator<Item = &'w Word>>(
+        &mut self,
+        words: I,
+    ) -> io::Result<()> {
+        for word in words {
+            self.write_word(word)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the header if no word was written, flushes, and gives back the
+    /// writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        if self.format != Format::JsonLines {
+            self.write_header()?;
+        }
+        self.out.flush()?;
+        Ok(self.out)
+    }
+
+    fn write_header(&mut self) -> io::Result<()> {
+        if self.header_written {
+            return Ok(());
+        }
+        self.header_written = true;
+        let header: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| column.name().to_owned())
+            .collect();
+        self.write_row(&header)
+    }
+
+    fn write_row(&mut self, fields: &[String]) -> io::Result<()> {
+        let (separator, escape): (&str, fn(&str) -> String) = match self.format {
+            Format::Csv => (",", csv_field),
+            _ => ("\t", tsv_field),
+        };
+        let line: Vec<String> = fields.iter().map(|field| escape(field)).collect();
+        writeln!(self.out, "{}", line.join(separator))
+    }
+}
+
+/// Quotes fields with commas, quotes or line breaks, doubling any quotes.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+/// TSV has no quoting, so tabs and line breaks become spaces.
+fn tsv_field(field: &str) -> String {
+    field.replace(['\t', '\n', '\r'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::{Column, Exporter, Format};
+    use crate::try_parse;
+    use crate::Word;
+
+    fn words() -> Vec<Word> {
+        vec![
+            try_parse::<Word>(
+                r#"{"word": "quote", "frequency": 4.2, "results": [
+                    {"definition": "a passage, \"repeated\"", "partOfSpeech": "noun",
+                     "synonyms": ["citation", "quotation"], "typeOf": ["passage"]},
+                    {"definition": "repeat a passage", "partOfSpeech": "verb",
+                     "examples": ["he quoted\tthe bible"]}
+                ]}"#,
+            )
+            .unwrap(),
+            try_parse::<Word>(r#"{"word": "zzz", "results": [{"definition": "sleep"}]}"#).unwrap(),
+            try_parse::<Word>(r#"{"word": "xyzzy", "frequency": 1.5, "results": []}"#).unwrap(),
+        ]
+    }
+
+    fn export(exporter: Exporter<Vec<u8>>) -> String {
+        let mut exporter = exporter;
+        exporter.write_words(&words()).unwrap();
+        String::from_utf8(exporter.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn it_writes_csv_rows_per_entry() {
+        assert_eq!(
+            export(Exporter::new(Vec::new(), Format::Csv)),
+            "word,sense,partOfSpeech,definition,synonyms,typeOf,examples,frequency\n\
+             quote,0,noun,\"a passage, \"\"repeated\"\"\",citation; quotation,passage,,4.2\n\
+             quote,1,verb,repeat a passage,,,he quoted\tthe bible,4.2\n\
+             zzz,0,,sleep,,,,\n\
+             xyzzy,,,,,,,1.5\n"
+        );
+    }
+
+    #[test]
+    fn it_writes_selected_tsv_columns() {
+        let exporter = Exporter::new(Vec::new(), Format::Tsv)
+            .columns(&[Column::Word, Column::Examples, Column::Synonyms])
+            .list_delimiter("|");
+        assert_eq!(
+            export(exporter),
+            "word\texamples\tsynonyms\n\
+             quote\t\tcitation|quotation\n\
+             quote\the quoted the bible\t\n\
+             zzz\t\t\n\
+             xyzzy\t\t\n"
+        );
+    }
+
+    #[test]
+    fn it_writes_json_lines_per_word() {
+        let output = export(Exporter::new(Vec::new(), Format::JsonLines));
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["results"][0]["synonyms"][1], "quotation");
+        assert_eq!(first["word"], "quote");
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::HashSet;
+use std::io::{self, Write};
+
+use crate::Entry;
+use crate::Word;
+
+/// The front of a card is the word and its pronunciation. The back has its
+/// definitions, each with the part of speech, followed by examples and
+/// synonyms, one item per line.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Card {
+    pub front: String,
+    pub back: String,
+    /// The parts of speech on the card.
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Deck {
+    pub name: String,
+    pub cards: Vec<Card>,
+}
+
+impl Deck {
+    /// Writes the deck as tab-separated front, back and tags, with the header
+    /// lines Anki reads on import. Line breaks become `<br>`, and whitespace
+    /// inside a tag becomes `_`.
+    pub fn write_anki_tsv<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        writeln!(out, "#separator:tab")?;
+        writeln!(out, "#html:true")?;
+        writeln!(out, "#deck:{}", anki_header(&self.name))?;
+        writeln!(out, "#tags column:3")?;
+        for card in &self.cards {
+            writeln!(
+                out,
+                "{}\t{}\t{}",
+                anki_field(&card.front),
+                anki_field(&card.back),
+                anki_tags(&card.tags)
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn write_json<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut *out, self)?;
+        writeln!(out)
+    }
+}
+
+/// Escapes HTML and turns tabs and line breaks into markup Anki shows.
+fn anki_field(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\t', " ")
+        .replace("\r\n", "\n")
+        .replace(['\r', '\n'], "<br>")
+}
+
+/// A header value on one line, since Anki reads the rest of the file as notes.
+fn anki_header(text: &str) -> String {
+    text.replace(['\t', '\r', '\n'], " ")
+}
+
+/// Anki separates tags with spaces, so spaces within one would split it.
+fn anki_tags(tags: &[String]) -> String {
+    tags.iter()
+        .map(|tag| tag.trim().replace(char::is_whitespace, "_"))
+        .filter(|tag| !tag.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Makes cards from words.
+///
+/// ```
+/// use wordsapi::flashcards::DeckBuilder;
+/// use wordsapi::{try_parse, Word};
+///
+/// let word = try_parse::<Word>(
+///     r#"{"word": "gregarious", "pronunciation": {"all": "ɡrɪ'ɡɛriəs"},
+///         "results": [{"definition": "fond of company", "partOfSpeech": "adjective"}]}"#,
+/// )
+/// .unwrap();
+/// let deck = DeckBuilder::new("GRE").build(&[word]);
+/// assert_eq!(deck.cards[0].front, "gregarious\n/ɡrɪ'ɡɛriəs/");
+/// assert_eq!(deck.cards[0].back, "(adjective) fond of company");
+/// ```
+#[derive(Debug, Clone)]
+pub struct DeckBuilder {
+    name: String,
+    part_of_speech: Option<String>,
+    per_sense: bool,
+    definitions: usize,
+    examples: usize,
+    synonyms: usize,
+}
+
+impl DeckBuilder {
+    /// One card per word, with up to 3 definitions, 2 examples and 5
+    /// synonyms.
+    pub fn new(name: &str) -> Self {
+        DeckBuilder {
+            name: name.to_owned(),
+            part_of_speech: None,
+            per_sense: false,
+            definitions: 3,
+            examples: 2,
+            synonyms: 5,
+        }
+    }
+
+    /// Only uses senses of this part of speech. Words without one get no card.
+    pub fn part_of_speech(mut self, part_of_speech: &str) -> Self {
+        self.part_of_speech = Some(part_of_speech.to_owned());
+        self
+    }
+
+    /// Makes a card for every sense rather than one for every word.
+    pub fn per_sense(mut self) -> Self {
+        self.per_sense = true;
+        self
+    }
+
+    /// The most definitions on a card for a word.
+    pub fn definitions(mut self, count: usize) -> Self {
+        self.definitions = count;
+        self
+    }
+
+    pub fn examples(mut self, count: usize) -> Self {
+        self.examples = count;
+        self
+    }
+
+    pub fn synonyms(mut self, count: usize) -> Self {
+        self.synonyms = count;
+        self
+    }
+
+    pub fn build(&self, words: &[Word]) -> Deck {
+        Deck {
+            name: self.name.clone(),
+            cards: words.iter().flat_map(|word| self.cards(word)).collect(),
+        }
+    }
+
+    pub fn cards(&self, word: &Word) -> Vec<Card> {
+        let entries: Vec<&Entry> = word
+            .entries
+            .iter()
+            .filter(|entry| {
+                self.part_of_speech.is_none()
+                    || entry.part_of_speech.as_deref() == self.part_of_speech.as_deref()
+            })
+            .collect();
+        if entries.is_empty() {
+            return Vec::new();
+        }
+        if self.per_sense {
+            entries
+                .iter()
+                .map(|entry| self.card(word, &[entry]))
+                .collect()
+        } else {
+            let shown = entries.len().min(self.definitions.max(1));
+            vec![self.card(word, &entries[..shown])]
+        }
+    }
+
+    fn card(&self, word: &Word, entries: &[&Entry]) -> Card {
+        let part_of_speech = match entries {
+            [entry] => entry.part_of_speech.as_deref(),
+            _ => self.part_of_speech.as_deref(),
+        };
+        let front = match word.pronunciation_of(part_of_speech) {
+            Some(pronunciation) => format!("{}\n/{}/", word.word, pronunciation),
+            None => word.word.clone(),
+        };
+
+        let mut back = Vec::new();
+        for (n, entry) in entries.iter().enumerate() {
+            let number = if entries.len() > 1 {
+                format!("{}. ", n + 1)
+            } else {
+                String::new()
+            };
+            match entry.part_of_speech {
+                Some(ref part_of_speech) => back.push(format!(
+                    "{}({}) {}",
+                    number, part_of_speech, entry.definition
+                )),
+                None => back.push(format!("{}{}", number, entry.definition)),
+            }
+        }
+        let examples = entries
+            .iter()
+            .flat_map(|entry| entry.examples.iter().flatten())
+            .take(self.examples);
+        for example in examples {
+            back.push(format!("e.g. {}", example));
+        }
+        let mut seen = HashSet::new();
+        let synonyms: Vec<&str> = entries
+            .iter()
+            .flat_map(|entry| entry.synonyms.iter().flatten())
+            .map(String::as_str)
+            .filter(|synonym| *synonym != word.word && seen.insert(*synonym))
+            .take(self.synonyms)
+            .collect();
+        if !synonyms.is_empty() {
+            back.push(format!("synonyms: {}", synonyms.join(", ")));
+        }
+
+        let mut tags: Vec<String> = Vec::new();
+        for entry in entries {
+            if let Some(ref part_of_speech) = entry.part_of_speech {
+                if !tags.contains(part_of_speech) {
+                    tags.push(part_of_speech.clone());
+                }
+            }
+        }
+        Card {
+            front,
+            back: back.join("\n"),
+            tags,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::flashcards::{Card, Deck, DeckBuilder};
+    use crate::try_parse;
+    use crate::Word;
+
+    fn words() -> Vec<Word> {
+        vec![
+            try_parse::<Word>(
+                r#"{"word": "record", "pronunciation": {"noun": "'rɛkərd", "verb": "rə'kɔrd"},
+                    "results": [
+                    {"definition": "anything providing permanent evidence", "partOfSpeech": "noun",
+                     "examples": ["the <official> record"], "synonyms": ["record", "archive"]},
+                    {"definition": "make a record of", "partOfSpeech": "verb",
+                     "examples": ["record the data"], "synonyms": ["enter", "put down"]},
+                    {"definition": "the best performance ever attested", "partOfSpeech": "noun",
+                     "synonyms": ["archive", "best"]}
+                ]}"#,
+            )
+            .unwrap(),
+            try_parse::<Word>(r#"{"word": "swiftly", "results": [{"definition": "in a swift manner", "partOfSpeech": "adverb"}]}"#)
+                .unwrap(),
+        ]
+    }
+
+    #[test]
+    fn it_makes_a_card_per_word() {
+        let deck = DeckBuilder::new("test")
+            .definitions(2)
+            .examples(1)
+            .synonyms(3)
+            .build(&words());
+        assert_eq!(deck.cards.len(), 2);
+        assert_eq!(deck.cards[0].front, "record\n/'rɛkərd/");
+        assert_eq!(
+            deck.cards[0].back,
+            "1. (noun) anything providing permanent evidence\n\
+             2. (verb) make a record of\n\
+             e.g. the <official> record\n\
+             synonyms: archive, enter, put down"
+        );
+        assert_eq!(deck.cards[0].tags, vec!["noun", "verb"]);
+    }
+
+    #[test]
+    fn it_makes_cards_per_sense_for_a_part_of_speech() {
+        let deck = DeckBuilder::new("test")
+            .part_of_speech("noun")
+            .per_sense()
+            .build(&words());
+        assert_eq!(deck.cards.len(), 2);
+        assert_eq!(deck.cards[0].front, "record\n/'rɛkərd/");
+        assert_eq!(
+            deck.cards[1].back,
+            "(noun) the best performance ever attested\nsynonyms: archive, best"
+        );
+    }
+
+    #[test]
+    fn it_writes_anki_tsv() {
+        let deck = DeckBuilder::new("Vocab").examples(1).build(&words());
+        let mut out = Vec::new();
+        deck.write_anki_tsv(&mut out).unwrap();
+        let tsv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = tsv.lines().collect();
+        assert_eq!(
+            &lines[..4],
+            [
+                "#separator:tab",
+                "#html:true",
+                "#deck:Vocab",
+                "#tags column:3"
+            ]
+        );
+        assert!(lines[4].starts_with("record<br>/'rɛkərd/\t1. (noun) anything"));
+        assert!(lines[4].contains("<br>e.g. the &lt;official&gt; record<br>"));
+        assert!(lines[4].ends_with("\tnoun verb"));
+        assert_eq!(lines[5], "swiftly\t(adverb) in a swift manner\tadverb");
+    }
+
+    #[test]
+    fn it_keeps_anki_notes_on_one_line() {
+        let deck = Deck {
+            name: "Vocab\nList".to_owned(),
+            cards: vec![Card {
+                front: "a\r\nb\rc".to_owned(),
+                back: "d\te".to_owned(),
+                tags: vec!["phrasal verb".to_owned(), "noun\n".to_owned()],
+            }],
+        };
+        let mut out = Vec::new();
+        deck.write_anki_tsv(&mut out).unwrap();
+        let tsv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = tsv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[2], "#deck:Vocab List");
+        assert_eq!(lines[4], "a<br>b<br>c\td e\tphrasal_verb noun");
+    }
+
+    #[test]
+    fn it_round_trips_json_decks() {
+        let deck = DeckBuilder::new("Vocab").build(&words());
+        let mut out = Vec::new();
+        deck.write_json(&mut out).unwrap();
+        let read: Deck = serde_json::from_slice(&out).unwrap();
+        assert_eq!(read, deck);
+    }
+}
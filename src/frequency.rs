@@ -0,0 +1,221 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::HasRequestType;
+use crate::Lookup;
+use crate::RequestError;
+use crate::RequestType;
+
+/// The reply to a `RequestType::Frequency` request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct WordFrequency {
+    pub word: String,
+    pub frequency: FrequencyDetails,
+}
+
+impl HasRequestType for WordFrequency {
+    fn request_type() -> RequestType {
+        RequestType::Frequency
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct FrequencyDetails {
+    /// Zipf scale, `log10` of occurrences per billion words: about 1 for the
+    /// rarest words up to 7 for the most common ones.
+    pub zipf: f32,
+    #[serde(rename = "perMillion")]
+    pub per_million: f32,
+    /// The share of documents the word appears in, from 0 to 1.
+    pub diversity: f32,
+}
+
+impl FrequencyDetails {
+    pub fn difficulty(&self) -> Difficulty {
+        Difficulty::from_zipf(self.zipf)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Difficulty {
+    /// Zipf 4 and above, roughly the 4,000 most frequent words.
+    Common,
+    /// Zipf from 2.5 up to 4.
+    Uncommon,
+    /// Zipf below 2.5, and words the API has no frequency for.
+    Rare,
+}
+
+impl Difficulty {
+    pub fn from_zipf(zipf: f32) -> Difficulty {
+        if zipf >= 4.0 {
+            Difficulty::Common
+        } else if zipf >= 2.5 {
+            Difficulty::Uncommon
+        } else {
+            Difficulty::Rare
+        }
+    }
+
+    /// The band's Zipf bounds as `frequency_min` and `frequency_max` search
+    /// filters. Bands share their boundaries, so the upper one is inclusive.
+    pub fn zipf_range(&self) -> (Option<f32>, Option<f32>) {
+        match *self {
+            Difficulty::Common => (Some(4.0), None),
+            Difficulty::Uncommon => (Some(2.5), Some(4.0)),
+            Difficulty::Rare => (None, Some(2.5)),
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Difficulty::Common => "common",
+            Difficulty::Uncommon => "uncommon",
+            Difficulty::Rare => "rare",
+        })
+    }
+}
+
+/// Frequency lookups for a list of words, each word fetched at most once.
+///
+/// ```no_run
+/// use wordsapi::frequency::Frequencies;
+/// use wordsapi::Client;
+///
+/// let client = Client::new("TOKEN");
+/// let frequencies = Frequencies::new(&client);
+/// let mut words = vec!["quixotic", "house", "ledger"];
+/// frequencies.sort(&mut words).unwrap();
+/// // ["house", "ledger", "quixotic"]
+/// ```
+pub struct Frequencies<'a, L: Lookup> {
+    lookup: &'a L,
+    known: RefCell<HashMap<String, Option<FrequencyDetails>>>,
+}
+
+impl<'a, L: Lookup> Frequencies<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        Self {
+            lookup,
+            known: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// The frequency of `word`, or `None` if the API does not know it.
+    pub fn details(&self, word: &str) -> Result<Option<FrequencyDetails>, RequestError> {
+        if let Some(details) = self.known.borrow().get(word) {
+            return Ok(*details);
+        }
+        let details = match self.lookup.look_up_parsed::<WordFrequency>(word) {
+            Ok(reply) => Some(reply.frequency),
+            Err(RequestError::NotFound { .. }) => None,
+            Err(e) => return Err(e),
+        };
+        self.known.borrow_mut().insert(word.to_owned(), details);
+        Ok(details)
+    }
+
+    pub fn difficulty(&self, word: &str) -> Result<Difficulty, RequestError> {
+        Ok(self
+            .details(word)?
+            .map_or(Difficulty::Rare, |details| details.difficulty()))
+    }
+
+    /// Orders `a` before `b` if it is more frequent. Unknown words come last.
+    pub fn compare(&self, a: &str, b: &str) -> Result<Ordering, RequestError> {
+        Ok(compare_zipf(self.zipf(a)?, self.zipf(b)?))
+    }
+
+    /// Sorts `words` most frequent first, keeping the order of ties. Unknown
+    /// words go last.
+    pub fn sort<S: AsRef<str>>(&self, words: &mut [S]) -> Result<(), RequestError> {
+        let mut zipfs = HashMap::new();
+        for word in words.iter() {
+            let word = word.as_ref();
+            zipfs.insert(word.to_owned(), self.zipf(word)?);
+        }
+        words.sort_by(|a, b| compare_zipf(zipfs[a.as_ref()], zipfs[b.as_ref()]));
+        Ok(())
+    }
+
+    fn zipf(&self, word: &str) -> Result<Option<f32>, RequestError> {
+        Ok(self.details(word)?.map(|details| details.zipf))
+    }
+}
+
+fn compare_zipf(a: Option<f32>, b: Option<f32>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => b.partial_cmp(&a).unwrap_or(Ordering::Equal),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::frequency::{Difficulty, Frequencies, WordFrequency};
+    use crate::lookup::fake::FakeLookup;
+    use crate::try_parse;
+    use crate::RequestType;
+
+    fn reply(word: &str, zipf: f32) -> String {
+        format!(
+            r#"{{"word": "{}", "frequency": {{"zipf": {}, "perMillion": 1.0, "diversity": 0.1}}}}"#,
+            word, zipf
+        )
+    }
+
+    fn lookup() -> FakeLookup {
+        FakeLookup::new()
+            .reply("house", RequestType::Frequency, &reply("house", 5.4))
+            .reply("ledger", RequestType::Frequency, &reply("ledger", 3.1))
+            .reply("quixotic", RequestType::Frequency, &reply("quixotic", 1.9))
+    }
+
+    #[test]
+    fn it_parses_frequency_details() {
+        let reply = try_parse::<WordFrequency>(
+            r#"{"word": "apple", "frequency": {"zipf": 4.23, "perMillion": 17.01, "diversity": 0.23}}"#,
+        )
+        .unwrap();
+        assert_eq!(reply.frequency.per_million, 17.01);
+        assert_eq!(reply.frequency.difficulty(), Difficulty::Common);
+    }
+
+    #[test]
+    fn it_bands_words_by_difficulty() {
+        let lookup = lookup();
+        let frequencies = Frequencies::new(&lookup);
+        assert_eq!(frequencies.difficulty("house").unwrap(), Difficulty::Common);
+        assert_eq!(
+            frequencies.difficulty("ledger").unwrap(),
+            Difficulty::Uncommon
+        );
+        assert_eq!(
+            frequencies.difficulty("quixotic").unwrap(),
+            Difficulty::Rare
+        );
+        assert_eq!(frequencies.difficulty("zzyzx").unwrap(), Difficulty::Rare);
+    }
+
+    #[test]
+    fn it_sorts_and_compares_with_one_lookup_per_word() {
+        let lookup = lookup();
+        let frequencies = Frequencies::new(&lookup);
+        let mut words = vec!["quixotic", "zzyzx", "house", "ledger", "house"];
+        frequencies.sort(&mut words).unwrap();
+        assert_eq!(words, vec!["house", "house", "ledger", "quixotic", "zzyzx"]);
+        assert_eq!(
+            frequencies.compare("ledger", "house").unwrap(),
+            Ordering::Greater
+        );
+        assert_eq!(lookup.fetches.get(), 4);
+    }
+}
@@ -0,0 +1,406 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::Lookup;
+use crate::RequestError;
+use crate::SearchQuery;
+use crate::Word;
+
+/// Results to ask for per search page; the API's maximum.
+const PAGE_SIZE: usize = 100;
+
+/// Turns a crossword pattern such as `?a??e`, with `?` for an unknown
+/// letter, into a `letterPattern` regular expression such as `^.a..e$`.
+pub fn letter_pattern(pattern: &str) -> Result<String, RequestError> {
+    let invalid = |reason| RequestError::InvalidWord {
+        word: pattern.to_owned(),
+        reason,
+    };
+    if pattern.is_empty() {
+        return Err(invalid("pattern is empty"));
+    }
+    let mut regex = String::from("^");
+    for c in pattern.chars() {
+        match c {
+            '?' => regex.push('.'),
+            c if c.is_alphabetic() => regex.extend(c.to_lowercase()),
+            _ => return Err(invalid("pattern may only contain letters and '?'")),
+        }
+    }
+    regex.push('$');
+    Ok(regex)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Clue {
+    pub answer: String,
+    /// A short definition of the answer, if clues were asked for and one was
+    /// found that does not give the answer away.
+    pub clue: Option<String>,
+}
+
+/// Finds crossword answers that fit a pattern.
+///
+/// ```no_run
+/// use wordsapi::games::Crossword;
+/// use wordsapi::Client;
+///
+/// let client = Client::new("TOKEN");
+/// let clues = Crossword::new(&client)
+///     .part_of_speech("noun")
+///     .frequency_min(3.0)
+///     .with_clues()
+///     .solve("?a??e")
+///     .unwrap();
+/// ```
+pub struct Crossword<'a, L: Lookup> {
+    lookup: &'a L,
+    part_of_speech: Option<String>,
+    frequency_min: Option<f32>,
+    frequency_max: Option<f32>,
+    clues: bool,
+    limit: usize,
+}
+
+impl<'a, L: Lookup> Crossword<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        Crossword {
+            lookup,
+            part_of_speech: None,
+            frequency_min: None,
+            frequency_max: None,
+            clues: false,
+            limit: 100,
+        }
+    }
+
+    pub fn part_of_speech(mut self, part_of_speech: &str) -> Self {
+        self.part_of_speech = Some(part_of_speech.to_owned());
+        self
+    }
+
+    pub fn frequency_min(mut self, zipf: f32) -> Self {
+        self.frequency_min = Some(zipf);
+        self
+    }
+
+    pub fn frequency_max(mut self, zipf: f32) -> Self {
+        self.frequency_max = Some(zipf);
+        self
+    }
+
+    /// Looks up each answer for a clue. This costs one request per answer,
+    /// and only answers with definitions are returned.
+    pub fn with_clues(mut self) -> Self {
+        self.clues = true;
+        self
+    }
+
+    /// The most answers to return. Defaults to 100; more than the API's 100
+    /// per page are fetched a page at a time.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// The search for `pattern`, without paging.
+    pub fn query(&self, pattern: &str) -> Result<SearchQuery, RequestError> {
+        let mut query = SearchQuery::new()
+            .letter_pattern(&letter_pattern(pattern)?)
+            .letters(pattern.chars().count());
+        query.part_of_speech = self.part_of_speech.clone();
+        query.frequency_min = self.frequency_min;
+        query.frequency_max = self.frequency_max;
+        if self.clues {
+            query = query.has_details("definitions");
+        }
+        Ok(query)
+    }
+
+    pub fn solve(&self, pattern: &str) -> Result<Vec<Clue>, RequestError> {
+        let answers = search_all(self.lookup, self.query(pattern)?, self.limit)?;
+        let mut clues = Vec::new();
+        for answer in answers {
+            let clue = if self.clues {
+                match self.lookup.look_up_parsed::<Word>(&answer) {
+                    Ok(word) => self.clue(&word),
+                    Err(RequestError::NotFound { .. }) => None,
+                    Err(e) => return Err(e),
+                }
+            } else {
+                None
+            };
+            clues.push(Clue { answer, clue });
+        }
+        Ok(clues)
+    }
+
+    /// The shortest definition of the wanted part of speech that does not
+    /// contain the answer.
+    fn clue(&self, word: &Word) -> Option<String> {
+        let answer = word.word.to_lowercase();
+        word.entries
+            .iter()
+            .filter(|entry| {
+                self.part_of_speech.is_none()
+                    || entry.part_of_speech.as_deref() == self.part_of_speech.as_deref()
+            })
+            .map(|entry| entry.definition.as_str())
+            .filter(|definition| !definition.to_lowercase().contains(&answer))
+            .min_by_key(|definition| definition.len())
+            .map(str::to_owned)
+    }
+}
+
+/// Words using exactly the given letters, such as `silent` and `tinsel` for
+/// `listen`. The letters themselves are left out if they spell a word.
+///
+/// Every page of matches is fetched, so giving the client a `Cache` saves
+/// repeating the searches for the same letters.
+pub fn find_anagrams<L: Lookup>(lookup: &L, letters: &str) -> Result<Vec<String>, RequestError> {
+    let rack = rack(letters)?;
+    let length = letters.chars().count();
+    let query = SearchQuery::new()
+        .letter_pattern(&format!("^[{}]{{{}}}$", letter_class(&rack), length))
+        .letters(length);
+    let letters = letters.to_lowercase();
+    let mut seen = HashSet::new();
+    Ok(search_all(lookup, query, usize::MAX)?
+        .into_iter()
+        .filter(|word| *word != letters && multiset(word) == rack)
+        .filter(|word| seen.insert(word.clone()))
+        .collect())
+}
+
+/// Words of at least `min_len` letters that can be spelled from the given
+/// letters, each letter used at most as often as it is given. Longer words
+/// come first.
+///
+/// Like `find_anagrams`, this fetches every page of matches.
+pub fn words_from_letters<L: Lookup>(
+    lookup: &L,
+    letters: &str,
+    min_len: usize,
+) -> Result<Vec<String>, RequestError> {
+    let rack = rack(letters)?;
+    let length = letters.chars().count();
+    let min_len = min_len.clamp(1, length);
+    let query = SearchQuery::new()
+        .letter_pattern(&format!(
+            "^[{}]{{{},{}}}$",
+            letter_class(&rack),
+            min_len,
+            length
+        ))
+        .letters_min(min_len)
+        .letters_max(length);
+    let mut words: Vec<String> = search_all(lookup, query, usize::MAX)?
+        .into_iter()
+        .filter(|word| fits(&multiset(word), &rack))
+        .collect();
+    words.sort_by(|a, b| {
+        b.chars()
+            .count()
+            .cmp(&a.chars().count())
+            .then_with(|| a.cmp(b))
+    });
+    words.dedup();
+    Ok(words)
+}
+
+fn rack(letters: &str) -> Result<HashMap<char, usize>, RequestError> {
+    if letters.is_empty() || !letters.chars().all(char::is_alphabetic) {
+        return Err(RequestError::InvalidWord {
+            word: letters.to_owned(),
+            reason: "letters may only contain letters",
+        });
+    }
+    Ok(multiset(letters))
+}
+
+fn multiset(word: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for c in word.chars().flat_map(char::to_lowercase) {
+        *counts.entry(c).or_insert(0) += 1;
+    }
+    counts
+}
+
+fn fits(word: &HashMap<char, usize>, rack: &HashMap<char, usize>) -> bool {
+    word.iter()
+        .all(|(c, n)| rack.get(c).is_some_and(|available| n <= available))
+}
+
+fn letter_class(rack: &HashMap<char, usize>) -> String {
+    let mut letters: Vec<char> = rack.keys().cloned().collect();
+    letters.sort_unstable();
+    letters.into_iter().collect()
+}
+
+/// Follows the pages of a search until every match, or `max` of them, is in.
+fn search_all<L: Lookup>(
+    lookup: &L,
+    query: SearchQuery,
+    max: usize,
+) -> Result<Vec<String>, RequestError> {
+    let page_size = PAGE_SIZE.min(max);
+    let mut words = Vec::new();
+    let mut page = 1;
+    while words.len() < max {
+        let results = lookup.search_parsed(&query.clone().limit(page_size).page(page))?;
+        let found = results.results.data.len();
+        words.extend(results.results.data);
+        if found == 0 || words.len() >= results.results.total {
+            break;
+        }
+        page += 1;
+    }
+    words.truncate(max);
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::games::{find_anagrams, letter_pattern, words_from_letters, Clue, Crossword};
+    use crate::lookup::fake::FakeLookup;
+    use crate::RequestError;
+    use crate::SearchQuery;
+
+    #[test]
+    fn it_converts_crossword_patterns() {
+        assert_eq!(letter_pattern("?a??e").unwrap(), "^.a..e$");
+        assert_eq!(letter_pattern("Café").unwrap(), "^café$");
+        assert!(matches!(
+            letter_pattern("a.b*"),
+            Err(RequestError::InvalidWord { .. })
+        ));
+        assert!(letter_pattern("").is_err());
+    }
+
+    #[test]
+    fn it_solves_with_clues() {
+        let empty = FakeLookup::new();
+        let crossword = Crossword::new(&empty)
+            .part_of_speech("noun")
+            .frequency_min(3.0)
+            .with_clues()
+            .limit(10);
+        let query = crossword.query("?a??e").unwrap();
+        assert_eq!(
+            query.query_string(),
+            "letterPattern=%5E.a..e%24&letters=5&partOfSpeech=noun&frequencyMin=3\
+             &hasDetails=definitions"
+        );
+        let lookup = FakeLookup::new()
+            .search_reply(
+                &query.limit(10).page(1),
+                r#"{"results": {"total": 3, "data": ["lance", "table", "waive"]}}"#,
+            )
+            .word(
+                r#"{"word": "table", "results": [
+                    {"definition": "a piece of furniture with a flat top", "partOfSpeech": "noun"},
+                    {"definition": "a set of data arranged in rows", "partOfSpeech": "noun"},
+                    {"definition": "hold back", "partOfSpeech": "verb"},
+                    {"definition": "a table of contents", "partOfSpeech": "noun"}
+                ]}"#,
+            )
+            .word(r#"{"word": "lance", "results": [{"definition": "a spear", "partOfSpeech": "noun"}]}"#);
+        let clues = Crossword::new(&lookup)
+            .part_of_speech("noun")
+            .frequency_min(3.0)
+            .with_clues()
+            .limit(10)
+            .solve("?a??e")
+            .unwrap();
+        assert_eq!(
+            clues,
+            vec![
+                Clue {
+                    answer: "lance".to_owned(),
+                    clue: Some("a spear".to_owned()),
+                },
+                Clue {
+                    answer: "table".to_owned(),
+                    clue: Some("a set of data arranged in rows".to_owned()),
+                },
+                Clue {
+                    answer: "waive".to_owned(),
+                    clue: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn it_pages_through_answers_up_to_the_limit() {
+        let page = |from: usize, to: usize| {
+            let words: Vec<String> = (from..to).map(|n| format!("\"w{:03}\"", n)).collect();
+            format!(
+                r#"{{"results": {{"total": 300, "data": [{}]}}}}"#,
+                words.join(",")
+            )
+        };
+        let empty = FakeLookup::new();
+        let query = Crossword::new(&empty).query("????").unwrap();
+        let lookup = FakeLookup::new()
+            .search_reply(&query.clone().limit(100).page(1), &page(0, 100))
+            .search_reply(&query.clone().limit(100).page(2), &page(100, 200))
+            .search_reply(&query.clone().limit(100).page(3), &page(200, 300));
+        let clues = Crossword::new(&lookup).limit(150).solve("????").unwrap();
+        assert_eq!(clues.len(), 150);
+        assert_eq!(clues[149].answer, "w149");
+        assert_eq!(lookup.queries.borrow().len(), 2);
+    }
+
+    #[test]
+    fn it_solves_without_lookups() {
+        let lookup = FakeLookup::new();
+        let clues = Crossword::new(&lookup).solve("c?t").unwrap();
+        assert!(clues.is_empty());
+        assert_eq!(lookup.fetches.get(), 0);
+        assert_eq!(
+            lookup.queries.borrow()[0].letter_pattern.as_deref(),
+            Some("^c.t$")
+        );
+    }
+
+    #[test]
+    fn it_finds_anagrams_across_pages() {
+        let query = SearchQuery::new()
+            .letter_pattern("^[eilnst]{6}$")
+            .letters(6)
+            .limit(100);
+        let lookup = FakeLookup::new()
+            .search_reply(
+                &query.clone().page(1),
+                r#"{"results": {"total": 5, "data": ["enlist", "listen", "tinsel"]}}"#,
+            )
+            .search_reply(
+                &query.clone().page(2),
+                r#"{"results": {"total": 6, "data": ["tinsel", "silent", "tiless"]}}"#,
+            );
+        assert_eq!(
+            find_anagrams(&lookup, "Listen").unwrap(),
+            vec!["enlist", "tinsel", "silent"]
+        );
+        assert_eq!(lookup.queries.borrow().len(), 2);
+    }
+
+    #[test]
+    fn it_finds_words_from_letters() {
+        let query = SearchQuery::new()
+            .letter_pattern("^[aert]{3,4}$")
+            .letters_min(3)
+            .letters_max(4)
+            .limit(100)
+            .page(1);
+        let lookup = FakeLookup::new().search_reply(
+            &query,
+            r#"{"results": {"total": 6, "data": ["tea", "rate", "tear", "teat", "art", "eat"]}}"#,
+        );
+        assert_eq!(
+            words_from_letters(&lookup, "rate", 3).unwrap(),
+            vec!["rate", "tear", "art", "eat", "tea"]
+        );
+        assert!(words_from_letters(&lookup, "r4te", 3).is_err());
+    }
+}
@@ -0,0 +1,389 @@
+pub mod export;
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use log::{debug, trace};
+
+use crate::Entry;
+use crate::Lookup;
+use crate::RequestError;
+use crate::Word;
+
+/// A lexical relation between a sense and another word, named after the
+/// `Entry` field it comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Relation {
+    /// `typeOf`: the sense is a kind of the other word.
+    Hypernym,
+    /// `hasTypes`: the other word is a kind of the sense.
+    Hyponym,
+    /// `partOf`
+    PartHolonym,
+    /// `hasParts`
+    PartMeronym,
+    /// `memberOf`
+    MemberHolonym,
+    /// `hasSubstances`
+    SubstanceMeronym,
+    Synonym,
+    Antonym,
+    SimilarTo,
+    PertainsTo,
+    VerbGroup,
+    Derivation,
+}
+
+impl Relation {
+    pub const ALL: [Relation; 12] = [
+        Relation::Hypernym,
+        Relation::Hyponym,
+        Relation::PartHolonym,
+        Relation::PartMeronym,
+        Relation::MemberHolonym,
+        Relation::SubstanceMeronym,
+        Relation::Synonym,
+        Relation::Antonym,
+        Relation::SimilarTo,
+        Relation::PertainsTo,
+        Relation::VerbGroup,
+        Relation::Derivation,
+    ];
+
+    /// The name of the `Entry` field, as the API spells it.
+    pub fn field_name(&self) -> &'static str {
+        match *self {
+            Relation::Hypernym => "typeOf",
+            Relation::Hyponym => "hasTypes",
+            Relation::PartHolonym => "partOf",
+            Relation::PartMeronym => "hasParts",
+            Relation::MemberHolonym => "memberOf",
+            Relation::SubstanceMeronym => "hasSubstances",
+            Relation::Synonym => "synonyms",
+            Relation::Antonym => "antonyms",
+            Relation::SimilarTo => "similarTo",
+            Relation::PertainsTo => "pertainsTo",
+            Relation::VerbGroup => "verbGroup",
+            Relation::Derivation => "derivation",
+        }
+    }
+
+    /// The words `entry` is related to by this relation.
+    pub fn targets<'e>(&self, entry: &'e Entry) -> &'e [String] {
+        let targets = match *self {
+            Relation::Hypernym => &entry.type_of,
+            Relation::Hyponym => &entry.has_types,
+            Relation::PartHolonym => &entry.part_of,
+            Relation::PartMeronym => &entry.has_parts,
+            Relation::MemberHolonym => &entry.member_of,
+            Relation::SubstanceMeronym => &entry.has_substances,
+            Relation::Synonym => &entry.synonyms,
+            Relation::Antonym => &entry.antonyms,
+            Relation::SimilarTo => &entry.similar_to,
+            Relation::PertainsTo => &entry.pertains_to,
+            Relation::VerbGroup => &entry.verb_group,
+            Relation::Derivation => &entry.derivation,
+        };
+        targets.as_ref().map_or(&[], Vec::as_slice)
+    }
+}
+
+pub type SenseId = usize;
+
+/// One entry of a word: a node of the graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sense {
+    pub word: String,
+    /// The position of the entry in `Word::entries`.
+    pub index: usize,
+    pub part_of_speech: Option<String>,
+    pub definition: String,
+}
+
+/// A relation from a sense to a word. The API does not say which sense of
+/// the target is meant, so edges point at words; `senses_of` finds the
+/// target's senses if it is in the graph.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+    pub from: SenseId,
+    pub relation: Relation,
+    pub to: String,
+}
+
+/// An in-memory semantic network with a node per sense and typed edges.
+#[derive(Debug, Default)]
+pub struct RelationGraph {
+    senses: Vec<Sense>,
+    edges: Vec<Edge>,
+    by_word: HashMap<String, Vec<SenseId>>,
+    missing: BTreeSet<String>,
+}
+
+impl RelationGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A graph of `words` with every relation, without further lookups.
+    pub fn from_words(words: &[Word]) -> Self {
+        let mut graph = Self::new();
+        for word in words {
+            graph.add_word(word, &Relation::ALL);
+        }
+        graph
+    }
+
+    /// Adds a node for every entry of `word` and an edge for each of the
+    /// given relations. A word that is already in the graph is left alone.
+    pub fn add_word(&mut self, word: &Word, relations: &[Relation]) -> Vec<SenseId> {
+        if self.by_word.contains_key(&word.word) {
+            return Vec::new();
+        }
+        let mut added = Vec::with_capacity(word.entries.len());
+        for (index, entry) in word.entries.iter().enumerate() {
+            let id = self.senses.len();
+            self.senses.push(Sense {
+                word: word.word.clone(),
+                index,
+                part_of_speech: entry.part_of_speech.clone(),
+                definition: entry.definition.clone(),
+            });
+            for relation in relations {
+                for target in relation.targets(entry) {
+                    self.edges.push(Edge {
+                        from: id,
+                        relation: *relation,
+                        to: target.clone(),
+                    });
+                }
+            }
+            added.push(id);
+        }
+        self.by_word.insert(word.word.clone(), added.clone());
+        added
+    }
+
+    pub fn senses(&self) -> &[Sense] {
+        &self.senses
+    }
+
+    pub fn sense(&self, id: SenseId) -> &Sense {
+        &self.senses[id]
+    }
+
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// The words in the graph, in no particular order.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.by_word.keys().map(String::as_str)
+    }
+
+    pub fn contains_word(&self, word: &str) -> bool {
+        self.by_word.contains_key(word)
+    }
+
+    pub fn senses_of(&self, word: &str) -> &[SenseId] {
+        self.by_word.get(word).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn edges_from(&self, id: SenseId) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.from == id)
+    }
+
+    pub fn edges_to<'g>(&'g self, word: &'g str) -> impl Iterator<Item = &'g Edge> + 'g {
+        self.edges.iter().filter(move |edge| edge.to == word)
+    }
+
+    /// Words that were looked up during a crawl but are unknown to the API.
+    pub fn missing(&self) -> &BTreeSet<String> {
+        &self.missing
+    }
+}
+
+/// Builds a `RelationGraph` by following relations breadth-first from seed
+/// words, one lookup per word.
+///
+/// ```no_run
+/// use wordsapi::graph::{Crawler, Relation};
+/// use wordsapi::Client;
+///
+/// let client = Client::new("TOKEN");
+/// let graph = Crawler::new(&client)
+///     .relations(&[Relation::Hypernym, Relation::Synonym])
+///     .max_depth(2)
+///     .budget(50)
+///     .crawl(&["dog", "cat"])
+///     .unwrap();
+/// println!("{} senses", graph.senses().len());
+/// ```
+pub struct Crawler<'a, L: Lookup> {
+    lookup: &'a L,
+    relations: Vec<Relation>,
+    max_depth: usize,
+    budget: usize,
+}
+
+impl<'a, L: Lookup> Crawler<'a, L> {
+    /// A crawler following every relation two steps out, with at most 100
+    /// lookups.
+    pub fn new(lookup: &'a L) -> Self {
+        Self {
+            lookup,
+            relations: Relation::ALL.to_vec(),
+            max_depth: 2,
+            budget: 100,
+        }
+    }
+
+    pub fn relations(mut self, relations: &[Relation]) -> Self {
+        self.relations = relations.to_vec();
+        self
+    }
+
+    /// How many relations away from a seed to go. Seeds are at depth 0.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// The most words to look up.
+    pub fn budget(mut self, budget: usize) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    /// Crawls from `seeds`. Words the API does not know are recorded in
+    /// `RelationGraph::missing`; any other failed lookup ends the crawl.
+    pub fn crawl<S: AsRef<str>>(&self, seeds: &[S]) -> Result<RelationGraph, RequestError> {
+        let mut graph = RelationGraph::new();
+        let mut queued: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<(String, usize)> = VecDeque::new();
+        for seed in seeds {
+            if queued.insert(seed.as_ref().to_owned()) {
+                queue.push_back((seed.as_ref().to_owned(), 0));
+            }
+        }
+        let mut lookups = 0;
+        while let Some((word, depth)) = queue.pop_front() {
+            if lookups >= self.budget {
+                debug!("crawl budget of {} lookups spent", self.budget);
+                break;
+            }
+            lookups += 1;
+            trace!("crawling {} at depth {}", word, depth);
+            let found = match self.lookup.look_up_parsed::<Word>(&word) {
+                Ok(found) => found,
+                Err(RequestError::NotFound { .. }) => {
+                    graph.missing.insert(word);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let added = graph.add_word(&found, &self.relations);
+            if depth >= self.max_depth {
+                continue;
+            }
+            let targets: Vec<String> = added
+                .iter()
+                .flat_map(|id| graph.edges_from(*id))
+                .map(|edge| edge.to.clone())
+                .collect();
+            for target in targets {
+                if queued.insert(target.clone()) {
+                    queue.push_back((target, depth + 1));
+                }
+            }
+        }
+        Ok(graph)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{Crawler, Relation, RelationGraph};
+    use crate::lookup::fake::FakeLookup;
+    use crate::try_parse;
+    use crate::Word;
+
+    fn animals() -> FakeLookup {
+        FakeLookup::new()
+            .word(
+                r#"{"word": "dog", "results": [
+                {"definition": "a domesticated canid", "partOfSpeech": "noun",
+                 "typeOf": ["canine", "domestic animal"], "hasParts": ["flag"],
+                 "synonyms": ["domestic dog"]},
+                {"definition": "a dull unattractive girl", "partOfSpeech": "noun",
+                 "typeOf": ["unpleasant woman"]}
+            ]}"#,
+            )
+            .word(
+                r#"{"word": "canine", "results": [
+                {"definition": "one of the four pointed teeth", "partOfSpeech": "noun",
+                 "typeOf": ["tooth"]},
+                {"definition": "any of various fissiped mammals", "partOfSpeech": "noun",
+                 "typeOf": ["carnivore"], "hasTypes": ["dog", "wolf"]}
+            ]}"#,
+            )
+            .word(
+                r#"{"word": "carnivore", "results": [
+                {"definition": "a terrestrial or aquatic flesh-eating mammal",
+                 "partOfSpeech": "noun", "typeOf": ["placental"], "hasTypes": ["canine"]}
+            ]}"#,
+            )
+    }
+
+    #[test]
+    fn it_builds_a_node_per_sense() {
+        let lookup = animals();
+        let graph = Crawler::new(&lookup).max_depth(0).crawl(&["dog"]).unwrap();
+        assert_eq!(graph.senses().len(), 2);
+        assert_eq!(graph.senses_of("dog"), &[0, 1]);
+        assert_eq!(graph.sense(1).definition, "a dull unattractive girl");
+        let hypernyms: Vec<&str> = graph
+            .edges_from(0)
+            .filter(|edge| edge.relation == Relation::Hypernym)
+            .map(|edge| edge.to.as_str())
+            .collect();
+        assert_eq!(hypernyms, vec!["canine", "domestic animal"]);
+    }
+
+    #[test]
+    fn it_crawls_breadth_first_to_max_depth() {
+        let lookup = animals();
+        let graph = Crawler::new(&lookup)
+            .relations(&[Relation::Hypernym])
+            .max_depth(2)
+            .crawl(&["dog"])
+            .unwrap();
+        assert!(graph.contains_word("canine"));
+        assert!(graph.contains_word("carnivore"));
+        assert!(graph.missing().contains("domestic animal"));
+        assert!(!graph.missing().contains("placental"));
+    }
+
+    #[test]
+    fn it_stops_at_the_budget() {
+        let lookup = animals();
+        let graph = Crawler::new(&lookup)
+            .relations(&[Relation::Hypernym])
+            .budget(2)
+            .crawl(&["dog"])
+            .unwrap();
+        assert_eq!(lookup.fetches.get(), 2);
+        assert!(graph.contains_word("canine"));
+        assert!(!graph.contains_word("carnivore"));
+    }
+
+    #[test]
+    fn it_builds_from_words() {
+        let dog = try_parse::<Word>(
+            r#"{"word": "dog", "results": [{"definition": "a canid", "synonyms": ["hound"]}]}"#,
+        )
+        .unwrap();
+        let graph = RelationGraph::from_words(&[dog]);
+        assert_eq!(graph.edges().len(), 1);
+        assert_eq!(graph.edges_to("hound").count(), 1);
+        assert_eq!(Relation::Synonym.field_name(), "synonyms");
+    }
+}
@@ -0,0 +1,339 @@
+use serde_json::{json, Map, Value};
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+use crate::graph::{Relation, RelationGraph, Sense, SenseId};
+use crate::normalize::percent_encode;
+
+static HAS_SENSE: &str = "hasSense";
+
+/// Every word in the graph, including words only reached by an edge, sorted
+/// so that output is stable.
+fn all_words(graph: &RelationGraph) -> BTreeSet<&str> {
+    graph
+        .words()
+        .chain(graph.edges().iter().map(|edge| edge.to.as_str()))
+        .collect()
+}
+
+/// Node IDs for DOT and GraphML. Words and senses are prefixed differently
+/// so that a word spelt like a sense ID stays a separate node.
+fn word_id(word: &str) -> String {
+    format!("w:{}", word)
+}
+
+fn sense_id(sense: &Sense) -> String {
+    format!("s:{}#{}", sense.word, sense.index + 1)
+}
+
+fn sense_label(sense: &Sense) -> String {
+    match sense.part_of_speech {
+        Some(ref part_of_speech) => {
+            format!("{} ({})\n{}", sense.word, part_of_speech, sense.definition)
+        }
+        None => format!("{}\n{}", sense.word, sense.definition),
+    }
+}
+
+/// Writes the graph as Graphviz DOT.
+///
+/// Every word is a node, linked to a node for each of its senses, and
+/// relations run from a sense to the related word, labelled with the `Entry`
+/// field they come from. Related words that were never looked up are word
+/// nodes without senses.
+pub fn write_dot<W: Write>(graph: &RelationGraph, out: &mut W) -> io::Result<()> {
+    writeln!(out, "digraph words {{")?;
+    for word in all_words(graph) {
+        writeln!(
+            out,
+            "  {} [shape=box, label={}];",
+            dot_string(&word_id(word)),
+            dot_string(word)
+        )?;
+        for id in graph.senses_of(word) {
+            let sense = graph.sense(*id);
+            writeln!(
+                out,
+                "  {} [label={}];",
+                dot_string(&sense_id(sense)),
+                dot_string(&sense_label(sense))
+            )?;
+            writeln!(
+                out,
+                "  {} -> {} [label={}, style=dotted];",
+                dot_string(&word_id(word)),
+                dot_string(&sense_id(sense)),
+                dot_string(HAS_SENSE)
+            )?;
+        }
+    }
+    for edge in graph.edges() {
+        writeln!(
+            out,
+            "  {} -> {} [label={}];",
+            dot_string(&sense_id(graph.sense(edge.from))),
+            dot_string(&word_id(&edge.to)),
+            dot_string(edge.relation.field_name())
+        )?;
+    }
+    writeln!(out, "}}")
+}
+
+fn dot_string(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+pub fn write_graphml<W: Write>(graph: &RelationGraph, out: &mut W) -> io::Result<()> {
+    writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+    writeln!(
+        out,
+        r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+    )?;
+    for (id, domain) in &[
+        ("kind", "node"),
+        ("label", "node"),
+        ("partOfSpeech", "node"),
+        ("definition", "node"),
+        ("relation", "edge"),
+    ] {
+        writeln!(
+            out,
+            r#"  <key id="{0}" for="{1}" attr.name="{0}" attr.type="string"/>"#,
+            id, domain
+        )?;
+    }
+    writeln!(out, r#"  <graph id="words" edgedefault="directed">"#)?;
+    for word in all_words(graph) {
+        writeln!(out, r#"    <node id="{}">"#, xml_escape(&word_id(word)))?;
+        writeln!(out, r#"      <data key="kind">word</data>"#)?;
+        writeln!(
+            out,
+            r#"      <data key="label">{}</data>"#,
+            xml_escape(word)
+        )?;
+        writeln!(out, "    </node>")?;
+        for id in graph.senses_of(word) {
+            let sense = graph.sense(*id);
+            writeln!(out, r#"    <node id="{}">"#, xml_escape(&sense_id(sense)))?;
+            writeln!(out, r#"      <data key="kind">sense</data>"#)?;
+            writeln!(
+                out,
+                r#"      <data key="label">{}</data>"#,
+                xml_escape(word)
+            )?;
+            if let Some(ref part_of_speech) = sense.part_of_speech {
+                writeln!(
+                    out,
+                    r#"      <data key="partOfSpeech">{}</data>"#,
+                    xml_escape(part_of_speech)
+                )?;
+            }
+            writeln!(
+                out,
+                r#"      <data key="definition">{}</data>"#,
+                xml_escape(&sense.definition)
+            )?;
+            writeln!(out, "    </node>")?;
+            write_graphml_edge(out, &word_id(word), &sense_id(sense), HAS_SENSE)?;
+        }
+    }
+    for edge in graph.edges() {
+        write_graphml_edge(
+            out,
+            &sense_id(graph.sense(edge.from)),
+            &word_id(&edge.to),
+            edge.relation.field_name(),
+        )?;
+    }
+    writeln!(out, "  </graph>")?;
+    writeln!(out, "</graphml>")
+}
+
+fn write_graphml_edge<W: Write>(
+    out: &mut W,
+    source: &str,
+    target: &str,
+    relation: &str,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        r#"    <edge source="{}" target="{}"><data key="relation">{}</data></edge>"#,
+        xml_escape(source),
+        xml_escape(target),
+        relation
+    )
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Writes the graph as JSON-LD, describing senses as SKOS concepts.
+///
+/// `typeOf` and `hasTypes` map to `skos:broader` and `skos:narrower`;
+/// the other relations keep their field names in a `wordsapi:` vocabulary.
+pub fn write_json_ld<W: Write>(graph: &RelationGraph, out: &mut W) -> io::Result<()> {
+    let mut context = Map::new();
+    context.insert("skos".into(), json!("http://www.w3.org/2004/02/skos/core#"));
+    context.insert("wordsapi".into(), json!("urn:wordsapi:vocab:"));
+    context.insert("prefLabel".into(), json!("skos:prefLabel"));
+    context.insert("definition".into(), json!("skos:definition"));
+    context.insert("partOfSpeech".into(), json!("wordsapi:partOfSpeech"));
+    context.insert(
+        HAS_SENSE.into(),
+        json!({"@id": "wordsapi:hasSense", "@type": "@id"}),
+    );
+    for relation in Relation::ALL.iter() {
+        let iri = match *relation {
+            Relation::Hypernym => "skos:broader".to_owned(),
+            Relation::Hyponym => "skos:narrower".to_owned(),
+            _ => format!("wordsapi:{}", relation.field_name()),
+        };
+        context.insert(
+            relation.field_name().into(),
+            json!({"@id": iri, "@type": "@id"}),
+        );
+    }
+    let mut nodes = Vec::new();
+    for word in all_words(graph) {
+        let senses: Vec<Value> = graph
+            .senses_of(word)
+            .iter()
+            .map(|id| json!(sense_iri(graph.sense(*id))))
+            .collect();
+        nodes.push(json!({
+            "@id": word_iri(word),
+            "@type": "wordsapi:Word",
+            "prefLabel": word,
+            HAS_SENSE: senses,
+        }));
+        for id in graph.senses_of(word) {
+            nodes.push(json_ld_sense(graph, *id));
+        }
+    }
+    let document = json!({"@context": context, "@graph": nodes});
+    serde_json::to_writer_pretty(&mut *out, &document)?;
+    writeln!(out)
+}
+
+fn json_ld_sense(graph: &RelationGraph, id: SenseId) -> Value {
+    let sense = graph.sense(id);
+    let mut node = Map::new();
+    node.insert("@id".into(), json!(sense_iri(sense)));
+    node.insert("@type".into(), json!("skos:Concept"));
+    node.insert("prefLabel".into(), json!(sense.word));
+    node.insert("definition".into(), json!(sense.definition));
+    if let Some(ref part_of_speech) = sense.part_of_speech {
+        node.insert("partOfSpeech".into(), json!(part_of_speech));
+    }
+    for edge in graph.edges_from(id) {
+        let targets = node
+            .entry(edge.relation.field_name())
+            .or_insert_with(|| json!([]));
+        if let Value::Array(ref mut targets) = *targets {
+            targets.push(json!(word_iri(&edge.to)));
+        }
+    }
+    Value::Object(node)
+}
+
+fn word_iri(word: &str) -> String {
+    format!("urn:wordsapi:word:{}", percent_encode(word))
+}
+
+fn sense_iri(sense: &Sense) -> String {
+    format!(
+        "urn:wordsapi:sense:{}:{}",
+        percent_encode(&sense.word),
+        sense.index + 1
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::export::{write_dot, write_graphml, write_json_ld};
+    use crate::try_parse;
+    use crate::RelationGraph;
+    use crate::Word;
+    use serde_json::Value;
+
+    fn graph() -> RelationGraph {
+        let dog = try_parse::<Word>(
+            r#"{"word": "dog", "results": [
+                {"definition": "a \"domesticated\" canid", "partOfSpeech": "noun",
+                 "typeOf": ["canine"], "hasParts": ["flag"]}
+            ]}"#,
+        )
+        .unwrap();
+        RelationGraph::from_words(&[dog])
+    }
+
+    fn export(write: fn(&RelationGraph, &mut Vec<u8>) -> std::io::Result<()>) -> String {
+        let mut out = Vec::new();
+        write(&graph(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn it_writes_dot() {
+        let dot = export(write_dot);
+        assert!(dot.starts_with("digraph words {\n"));
+        assert!(dot.contains(r#"  "w:canine" [shape=box, label="canine"];"#));
+        assert!(dot.contains(r#"  "s:dog#1" [label="dog (noun)\na \"domesticated\" canid"];"#));
+        assert!(dot.contains(r#"  "s:dog#1" -> "w:canine" [label="typeOf"];"#));
+        assert!(dot.contains(r#"  "w:dog" -> "s:dog#1" [label="hasSense", style=dotted];"#));
+    }
+
+    #[test]
+    fn it_writes_graphml() {
+        let graphml = export(write_graphml);
+        assert!(graphml.contains(r#"<node id="w:dog">"#));
+        assert!(graphml.contains(r#"<node id="s:dog#1">"#));
+        assert!(graphml.contains("a &quot;domesticated&quot; canid"));
+        assert!(graphml.contains(
+            r#"<edge source="s:dog#1" target="w:flag"><data key="relation">hasParts</data></edge>"#
+        ));
+    }
+
+    #[test]
+    fn it_keeps_words_and_senses_apart() {
+        let dog = try_parse::<Word>(
+            r#"{"word": "dog", "results": [{"definition": "a canid", "typeOf": ["dog#1"]}]}"#,
+        )
+        .unwrap();
+        let mut out = Vec::new();
+        write_dot(&RelationGraph::from_words(&[dog]), &mut out).unwrap();
+        let dot = String::from_utf8(out).unwrap();
+        assert!(dot.contains(r#"  "s:dog#1" -> "w:dog#1" [label="typeOf"];"#));
+    }
+
+    #[test]
+    fn it_writes_json_ld() {
+        let document: Value = serde_json::from_str(&export(write_json_ld)).unwrap();
+        assert_eq!(document["@context"]["typeOf"]["@id"], "skos:broader");
+        let sense = document["@graph"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|node| node["@id"] == "urn:wordsapi:sense:dog:1")
+            .unwrap();
+        assert_eq!(sense["@type"], "skos:Concept");
+        assert_eq!(sense["typeOf"][0], "urn:wordsapi:word:canine");
+        assert_eq!(sense["hasParts"][0], "urn:wordsapi:word:flag");
+    }
+}
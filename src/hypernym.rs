@@ -0,0 +1,312 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::lookup::WordMemo;
+use crate::Entry;
+use crate::Lookup;
+use crate::RequestError;
+use crate::Word;
+
+/// A word followed by its hypernyms, up to the root.
+pub type HypernymPath = Rc<Vec<String>>;
+
+/// The hypernym two senses share, and how far each is from it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommonHypernym {
+    pub hypernym: String,
+    pub sense_a: usize,
+    pub sense_b: usize,
+    /// Steps from the first word up to the hypernym.
+    pub distance_a: usize,
+    /// Steps from the second word up to the hypernym.
+    pub distance_b: usize,
+    /// Nodes from the root down to the hypernym, counting both; the root has
+    /// depth 1.
+    pub depth: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Similarity {
+    pub common: CommonHypernym,
+    /// Edges on the shortest path between the two senses.
+    pub path_length: usize,
+    /// `1 / (1 + path_length)`: 1 for the same sense, approaching 0.
+    pub path: f64,
+    /// Wu-Palmer similarity, `2 * depth(common) / (depth(a) + depth(b))`.
+    pub wu_palmer: f64,
+}
+
+/// Follows `typeOf` relations up the hypernym hierarchy.
+///
+/// Words are looked up only when a query reaches them, and every word and
+/// path is remembered, so related queries share their lookups.
+///
+/// ```no_run
+/// use wordsapi::hypernym::Hypernyms;
+/// use wordsapi::Client;
+///
+/// let client = Client::new("TOKEN");
+/// let hypernyms = Hypernyms::new(&client);
+/// // ["dog", "canine", "carnivore", ..., "entity"]
+/// println!("{:?}", hypernyms.hypernym_path("dog", 0).unwrap());
+/// let common = hypernyms.lowest_common_hypernym("dog", "cat").unwrap();
+/// ```
+pub struct Hypernyms<'a, L: Lookup> {
+    max_depth: usize,
+    words: WordMemo<'a, L>,
+    paths: RefCell<HashMap<(String, usize), HypernymPath>>,
+}
+
+impl<'a, L: Lookup> Hypernyms<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        Self {
+            max_depth: 32,
+            words: WordMemo::new(lookup),
+            paths: RefCell::new(HashMap::new()),
+        }
+    }
+
+    /// The most hypernyms to follow from any word. Defaults to 32.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// The chain from `word` up to its root hypernym, starting with `word`
+    /// itself. `sense` indexes `Word::entries`; a sense the word does not
+    /// have, or a word the API does not know, gives an empty path.
+    ///
+    /// Where a sense has several hypernyms the first is followed, and of the
+    /// hypernym's senses the one listing the previous word in `hasTypes` is
+    /// preferred.
+    pub fn hypernym_path(&self, word: &str, sense: usize) -> Result<HypernymPath, RequestError> {
+        let key = (word.to_owned(), sense);
+        if let Some(path) = self.paths.borrow().get(&key) {
+            return Ok(path.clone());
+        }
+        let path = Rc::new(self.find_path(word, sense)?);
+        self.paths.borrow_mut().insert(key, path.clone());
+        Ok(path)
+    }
+
+    fn find_path(&self, word: &str, sense: usize) -> Result<Vec<String>, RequestError> {
+        let mut current = match self.words.word(word)? {
+            Some(current) => current,
+            None => return Ok(Vec::new()),
+        };
+        let mut entry_index = sense;
+        if entry_index >= current.entries.len() {
+            return Ok(Vec::new());
+        }
+        let mut path = vec![current.word.clone()];
+        let mut seen: HashSet<String> = path.iter().cloned().collect();
+        while path.len() <= self.max_depth {
+            let entry = &current.entries[entry_index];
+            let next = match entry.type_of.as_ref().and_then(|types| types.first()) {
+                Some(next) if !seen.contains(next) => next.clone(),
+                _ => break,
+            };
+            seen.insert(next.clone());
+            path.push(next.clone());
+            let next_word = match self.words.word(&next)? {
+                Some(next_word) => next_word,
+                None => break,
+            };
+            entry_index = match pick_sense(&next_word, &current.word, entry) {
+                Some(index) => index,
+                None => break,
+            };
+            current = next_word;
+        }
+        Ok(path)
+    }
+
+    /// The nearest hypernym shared by any sense of `a` and any sense of `b`,
+    /// or `None` if their hierarchies never meet.
+    pub fn lowest_common_hypernym(
+        &self,
+        a: &str,
+        b: &str,
+    ) -> Result<Option<CommonHypernym>, RequestError> {
+        let senses_a = self.sense_count(a)?;
+        let senses_b = self.sense_count(b)?;
+        let mut best: Option<CommonHypernym> = None;
+        for sense_a in 0..senses_a {
+            let path_a = self.hypernym_path(a, sense_a)?;
+            for sense_b in 0..senses_b {
+                let path_b = self.hypernym_path(b, sense_b)?;
+                let common = path_a
+                    .iter()
+                    .enumerate()
+                    .find_map(|(distance_a, hypernym)| {
+                        path_b
+                            .iter()
+                            .position(|other| other == hypernym)
+                            .map(|distance_b| CommonHypernym {
+                                hypernym: hypernym.clone(),
+                                sense_a,
+                                sense_b,
+                                distance_a,
+                                distance_b,
+                                depth: path_a.len() - distance_a,
+                            })
+                    });
+                if let Some(common) = common {
+                    let closer = match best {
+                        Some(ref best) => {
+                            common.distance_a + common.distance_b
+                                < best.distance_a + best.distance_b
+                        }
+                        None => true,
+                    };
+                    if closer {
+                        best = Some(common);
+                    }
+                }
+            }
+        }
+        Ok(best)
+    }
+
+    /// Path-based similarity of the closest senses of `a` and `b`.
+    pub fn similarity(&self, a: &str, b: &str) -> Result<Option<Similarity>, RequestError> {
+        let common = match self.lowest_common_hypernym(a, b)? {
+            Some(common) => common,
+            None => return Ok(None),
+        };
+        let path_length = common.distance_a + common.distance_b;
+        let depth_a = common.depth + common.distance_a;
+        let depth_b = common.depth + common.distance_b;
+        Ok(Some(Similarity {
+            path_length,
+            path: 1.0 / (1.0 + path_length as f64),
+            wu_palmer: 2.0 * common.depth as f64 / (depth_a + depth_b) as f64,
+            common,
+        }))
+    }
+
+    fn sense_count(&self, word: &str) -> Result<usize, RequestError> {
+        Ok(self.words.word(word)?.map_or(0, |word| word.entries.len()))
+    }
+}
+
+/// The sense of `hypernym` that `hyponym`'s `entry` most likely refers to.
+fn pick_sense(hypernym: &Word, hyponym: &str, entry: &Entry) -> Option<usize> {
+    let lists = |candidate: &Entry| {
+        candidate
+            .has_types
+            .as_ref()
+            .is_some_and(|types| types.iter().any(|t| t == hyponym))
+    };
+    let same_part_of_speech = |candidate: &Entry| candidate.part_of_speech == entry.part_of_speech;
+    hypernym
+        .entries
+        .iter()
+        .position(lists)
+        .or_else(|| hypernym.entries.iter().position(same_part_of_speech))
+        .or(if hypernym.entries.is_empty() {
+            None
+        } else {
+            Some(0)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::hypernym::Hypernyms;
+    use crate::lookup::fake::FakeLookup;
+
+    fn taxonomy() -> FakeLookup {
+        let entry = |word: &str, type_of: &str, has_types: &str| {
+            format!(
+                r#"{{"word": "{}", "results": [{{"definition": "d", "partOfSpeech": "noun", "typeOf": [{}], "hasTypes": [{}]}}]}}"#,
+                word, type_of, has_types
+            )
+        };
+        FakeLookup::new()
+            .word(
+                r#"{"word": "dog", "results": [
+                {"definition": "a dull girl", "partOfSpeech": "noun", "typeOf": ["woman"]},
+                {"definition": "a canid", "partOfSpeech": "noun", "typeOf": ["canine"]}
+            ]}"#,
+            )
+            .word(
+                r#"{"word": "canine", "results": [
+                {"definition": "a tooth", "partOfSpeech": "noun", "typeOf": ["tooth"]},
+                {"definition": "a canid", "partOfSpeech": "noun", "typeOf": ["carnivore"],
+                 "hasTypes": ["dog", "wolf"]}
+            ]}"#,
+            )
+            .word(&entry("cat", r#""feline""#, ""))
+            .word(&entry("feline", r#""carnivore""#, r#""cat""#))
+            .word(&entry("carnivore", r#""mammal""#, r#""canine", "feline""#))
+            .word(&entry("mammal", r#""animal""#, r#""carnivore""#))
+            .word(&entry("animal", "", r#""mammal""#))
+            .word(&entry("tooth", r#""bone""#, ""))
+    }
+
+    #[test]
+    fn it_follows_type_of_to_the_root() {
+        let lookup = taxonomy();
+        let hypernyms = Hypernyms::new(&lookup);
+        assert_eq!(
+            *hypernyms.hypernym_path("dog", 1).unwrap(),
+            vec!["dog", "canine", "carnivore", "mammal", "animal"]
+        );
+        assert_eq!(
+            *hypernyms.hypernym_path("dog", 0).unwrap(),
+            vec!["dog", "woman"]
+        );
+        assert!(hypernyms.hypernym_path("dog", 2).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_memoizes_lookups() {
+        let lookup = taxonomy();
+        let hypernyms = Hypernyms::new(&lookup);
+        hypernyms.hypernym_path("dog", 1).unwrap();
+        let fetches = lookup.fetches.get();
+        hypernyms.hypernym_path("dog", 1).unwrap();
+        hypernyms.hypernym_path("canine", 1).unwrap();
+        assert_eq!(lookup.fetches.get(), fetches);
+    }
+
+    #[test]
+    fn it_finds_the_lowest_common_hypernym() {
+        let lookup = taxonomy();
+        let hypernyms = Hypernyms::new(&lookup);
+        let common = hypernyms
+            .lowest_common_hypernym("dog", "cat")
+            .unwrap()
+            .unwrap();
+        assert_eq!(common.hypernym, "carnivore");
+        assert_eq!(common.sense_a, 1);
+        assert_eq!(common.distance_a, 2);
+        assert_eq!(common.distance_b, 2);
+        assert_eq!(common.depth, 3);
+    }
+
+    #[test]
+    fn it_scores_similarity() {
+        let lookup = taxonomy();
+        let hypernyms = Hypernyms::new(&lookup);
+        let similarity = hypernyms.similarity("dog", "cat").unwrap().unwrap();
+        assert_eq!(similarity.path_length, 4);
+        assert!((similarity.path - 0.2).abs() < 1e-9);
+        assert!((similarity.wu_palmer - 0.6).abs() < 1e-9);
+        let same = hypernyms.similarity("dog", "dog").unwrap().unwrap();
+        assert!((same.wu_palmer - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_handles_unknown_words() {
+        let lookup = taxonomy();
+        let hypernyms = Hypernyms::new(&lookup);
+        assert!(hypernyms.hypernym_path("zzz", 0).unwrap().is_empty());
+        assert_eq!(
+            hypernyms.lowest_common_hypernym("dog", "zzz").unwrap(),
+            None
+        );
+    }
+}
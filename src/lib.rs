@@ -8,18 +8,95 @@ extern crate serde_json;
 
 pub mod word;
 pub use self::word::Entry;
+pub use self::word::EntryRef;
+pub use self::word::Syllables;
+pub use self::word::SyllablesRef;
 pub use self::word::Word;
+pub use self::word::WordRef;
 
 pub mod error;
+pub use self::error::ConfigError;
 pub use self::error::RequestError;
 
+pub mod cache;
+pub use self::cache::Cache;
+
+pub mod lookup;
+pub use self::lookup::Lookup;
+
+pub mod graph;
+pub use self::graph::RelationGraph;
+
+pub mod hypernym;
+pub use self::hypernym::Hypernyms;
+
+pub mod frequency;
+pub use self::frequency::Difficulty;
+pub use self::frequency::Frequencies;
+pub use self::frequency::FrequencyDetails;
+
+pub mod morphology;
+pub use self::morphology::Lemmatizer;
+
+pub mod readability;
+pub use self::readability::Readability;
+
+pub mod spell;
+pub use self::spell::suggest;
+
+pub mod thesaurus;
+pub use self::thesaurus::Thesaurus;
+
+pub mod daily;
+pub use self::daily::DailyWords;
+
+pub mod diff;
+pub use self::diff::WordDiff;
+
+pub mod export;
+pub use self::export::Exporter;
+
+pub mod flashcards;
+pub use self::flashcards::DeckBuilder;
+
+pub mod games;
+pub use self::games::Crossword;
+
+pub mod rhymes;
+pub use self::rhymes::Rhymes;
+
+pub mod disambiguate;
+pub use self::disambiguate::disambiguate;
+
+pub mod config;
+pub use self::config::Config;
+pub use self::config::Profile;
+
 pub mod client;
 pub use self::client::Client;
 
+pub mod normalize;
+pub use self::normalize::Normalization;
+
+pub mod retry;
+pub use self::retry::RetryPolicy;
+
 pub mod request;
 pub use self::request::HasRequestType;
 pub use self::request::RequestType;
+pub use self::request::UnknownRequestType;
+
+pub mod secret;
+pub use self::secret::Secret;
+
+pub mod search;
+pub use self::search::SearchMatches;
+pub use self::search::SearchQuery;
+pub use self::search::SearchResults;
 
 pub mod response;
 pub use self::response::try_parse;
+pub use self::response::try_parse_borrowed;
+pub use self::response::try_parse_lenient;
+pub use self::response::ParseWarning;
 pub use self::response::Response;
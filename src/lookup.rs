@@ -0,0 +1,168 @@
+use serde::de::DeserializeOwned;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use crate::Client;
+use crate::HasRequestType;
+use crate::RequestError;
+use crate::SearchQuery;
+use crate::SearchResults;
+use crate::Word;
+
+/// Parsed API replies, without the HTTP details of a `Response`.
+///
+/// `Client` is the real source. The higher-level helpers in this crate take
+/// any `Lookup`, so they can also run against recorded data.
+pub trait Lookup {
+    /// The parsed reply of the endpoint for `T`, like `Client::look_up`.
+    fn look_up_parsed<T: DeserializeOwned + HasRequestType>(
+        &self,
+        word: &str,
+    ) -> Result<T, RequestError>;
+
+    /// The parsed reply of a search, like `Client::search`.
+    fn search_parsed(&self, query: &SearchQuery) -> Result<SearchResults, RequestError>;
+
+    /// A random word matching the filters of `query`, like
+    /// `Client::random_matching`.
+    fn random_matching_parsed(&self, query: &SearchQuery) -> Result<Word, RequestError>;
+}
+
+impl Lookup for Client {
+    fn look_up_parsed<T: DeserializeOwned + HasRequestType>(
+        &self,
+        word: &str,
+    ) -> Result<T, RequestError> {
+        self.look_up::<T>(word)?.result
+    }
+
+    fn search_parsed(&self, query: &SearchQuery) -> Result<SearchResults, RequestError> {
+        Client::search(self, query)?.result
+    }
+
+    fn random_matching_parsed(&self, query: &SearchQuery) -> Result<Word, RequestError> {
+        self.random_matching(query)?.result
+    }
+}
+
+/// Words fetched through a `Lookup`, each at most once. Words the API does
+/// not know are remembered as `None`.
+pub(crate) struct WordMemo<'a, L: Lookup> {
+    lookup: &'a L,
+    words: RefCell<HashMap<String, Option<Rc<Word>>>>,
+}
+
+impl<'a, L: Lookup> WordMemo<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        Self {
+            lookup,
+            words: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn word(&self, word: &str) -> Result<Option<Rc<Word>>, RequestError> {
+        if let Some(found) = self.words.borrow().get(word) {
+            return Ok(found.clone());
+        }
+        let found = match self.lookup.look_up_parsed::<Word>(word) {
+            Ok(found) => Some(Rc::new(found)),
+            Err(RequestError::NotFound { .. }) => None,
+            Err(e) => return Err(e),
+        };
+        self.words
+            .borrow_mut()
+            .insert(word.to_owned(), found.clone());
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fake {
+    use serde::de::DeserializeOwned;
+    use std::cell::{Cell, RefCell};
+    use std::collections::{HashMap, VecDeque};
+
+    use crate::normalize::validate;
+    use crate::try_parse;
+    use crate::HasRequestType;
+    use crate::Lookup;
+    use crate::RequestError;
+    use crate::RequestType;
+    use crate::SearchQuery;
+    use crate::SearchResults;
+    use crate::Word;
+
+    /// Canned replies keyed by word and request type, or by search query.
+    /// Words are validated as `Client` does. Other searches find nothing. Random words are handed out in order
+    /// until they run out.
+    #[derive(Default)]
+    pub struct FakeLookup {
+        replies: HashMap<(String, RequestType), String>,
+        search_replies: HashMap<String, String>,
+        random_words: RefCell<VecDeque<String>>,
+        pub fetches: Cell<usize>,
+        pub queries: RefCell<Vec<SearchQuery>>,
+    }
+
+    impl FakeLookup {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        pub fn reply(mut self, word: &str, request_type: RequestType, json: &str) -> Self {
+            self.replies
+                .insert((word.to_owned(), request_type), json.to_owned());
+            self
+        }
+
+        pub fn search_reply(mut self, query: &SearchQuery, json: &str) -> Self {
+            self.search_replies
+                .insert(query.query_string(), json.to_owned());
+            self
+        }
+
+        pub fn random_word(self, json: &str) -> Self {
+            self.random_words.borrow_mut().push_back(json.to_owned());
+            self
+        }
+
+        pub fn word(self, json: &str) -> Self {
+            let word: serde_json::Value = serde_json::from_str(json).unwrap();
+            let name = word["word"].as_str().unwrap().to_owned();
+            self.reply(&name, RequestType::Word, json)
+        }
+    }
+
+    impl Lookup for FakeLookup {
+        fn look_up_parsed<T: DeserializeOwned + HasRequestType>(
+            &self,
+            word: &str,
+        ) -> Result<T, RequestError> {
+            validate(word)?;
+            self.fetches.set(self.fetches.get() + 1);
+            match self.replies.get(&(word.to_owned(), T::request_type())) {
+                Some(json) => try_parse::<T>(json),
+                None => Err(RequestError::NotFound {
+                    word: word.to_owned(),
+                }),
+            }
+        }
+
+        fn search_parsed(&self, query: &SearchQuery) -> Result<SearchResults, RequestError> {
+            self.queries.borrow_mut().push(query.clone());
+            match self.search_replies.get(&query.query_string()) {
+                Some(json) => try_parse::<SearchResults>(json),
+                None => try_parse::<SearchResults>(r#"{"results":{"total":0,"data":[]}}"#),
+            }
+        }
+
+        fn random_matching_parsed(&self, query: &SearchQuery) -> Result<Word, RequestError> {
+            self.queries.borrow_mut().push(query.clone());
+            match self.random_words.borrow_mut().pop_front() {
+                Some(json) => try_parse::<Word>(&json),
+                None => Err(RequestError::RequestError),
+            }
+        }
+    }
+}
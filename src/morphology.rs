@@ -0,0 +1,402 @@
+use std::collections::HashSet;
+
+use crate::Lookup;
+use crate::RequestError;
+use crate::Word;
+
+static IRREGULAR: &[(&str, &str)] = &[
+    ("am", "be"),
+    ("are", "be"),
+    ("is", "be"),
+    ("was", "be"),
+    ("were", "be"),
+    ("been", "be"),
+    ("has", "have"),
+    ("had", "have"),
+    ("did", "do"),
+    ("done", "do"),
+    ("went", "go"),
+    ("gone", "go"),
+    ("ran", "run"),
+    ("saw", "see"),
+    ("seen", "see"),
+    ("came", "come"),
+    ("took", "take"),
+    ("taken", "take"),
+    ("gave", "give"),
+    ("given", "give"),
+    ("ate", "eat"),
+    ("eaten", "eat"),
+    ("wrote", "write"),
+    ("written", "write"),
+    ("spoke", "speak"),
+    ("spoken", "speak"),
+    ("broke", "break"),
+    ("broken", "break"),
+    ("chose", "choose"),
+    ("chosen", "choose"),
+    ("began", "begin"),
+    ("begun", "begin"),
+    ("sang", "sing"),
+    ("sung", "sing"),
+    ("swam", "swim"),
+    ("swum", "swim"),
+    ("drank", "drink"),
+    ("drunk", "drink"),
+    ("knew", "know"),
+    ("known", "know"),
+    ("grew", "grow"),
+    ("grown", "grow"),
+    ("flew", "fly"),
+    ("flown", "fly"),
+    ("drove", "drive"),
+    ("driven", "drive"),
+    ("rode", "ride"),
+    ("ridden", "ride"),
+    ("fell", "fall"),
+    ("fallen", "fall"),
+    ("froze", "freeze"),
+    ("frozen", "freeze"),
+    ("stole", "steal"),
+    ("stolen", "steal"),
+    ("bought", "buy"),
+    ("brought", "bring"),
+    ("thought", "think"),
+    ("taught", "teach"),
+    ("caught", "catch"),
+    ("fought", "fight"),
+    ("sought", "seek"),
+    ("made", "make"),
+    ("said", "say"),
+    ("paid", "pay"),
+    ("laid", "lay"),
+    ("left", "leave"),
+    ("felt", "feel"),
+    ("kept", "keep"),
+    ("slept", "sleep"),
+    ("meant", "mean"),
+    ("sent", "send"),
+    ("spent", "spend"),
+    ("built", "build"),
+    ("lost", "lose"),
+    ("met", "meet"),
+    ("sat", "sit"),
+    ("stood", "stand"),
+    ("understood", "understand"),
+    ("told", "tell"),
+    ("sold", "sell"),
+    ("found", "find"),
+    ("held", "hold"),
+    ("led", "lead"),
+    ("fed", "feed"),
+    ("won", "win"),
+    ("got", "get"),
+    ("forgot", "forget"),
+    ("children", "child"),
+    ("men", "man"),
+    ("women", "woman"),
+    ("people", "person"),
+    ("feet", "foot"),
+    ("teeth", "tooth"),
+    ("geese", "goose"),
+    ("mice", "mouse"),
+    ("lice", "louse"),
+    ("oxen", "ox"),
+    ("dice", "die"),
+    ("criteria", "criterion"),
+    ("phenomena", "phenomenon"),
+    ("data", "datum"),
+    ("cacti", "cactus"),
+    ("fungi", "fungus"),
+    ("nuclei", "nucleus"),
+    ("theses", "thesis"),
+    ("analyses", "analysis"),
+    ("crises", "crisis"),
+    ("wives", "wife"),
+    ("knives", "knife"),
+    ("lives", "life"),
+    ("leaves", "leaf"),
+    ("wolves", "wolf"),
+    ("halves", "half"),
+    ("better", "good"),
+    ("best", "good"),
+    ("worse", "bad"),
+    ("worst", "bad"),
+    ("further", "far"),
+    ("farther", "far"),
+    ("more", "many"),
+    ("most", "many"),
+    ("less", "little"),
+    ("least", "little"),
+];
+
+/// How a lemma was derived from the requested word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    /// The requested word itself.
+    Exact,
+    Irregular,
+    /// A suffix rule, such as `-ing`.
+    Suffix(&'static str),
+}
+
+/// The lemmas `word` might be an inflection of, likeliest first, without
+/// `word` itself. The suffix rules overgenerate ("string" gives "str"), so
+/// a candidate is only a lemma if the API knows it.
+pub fn candidate_lemmas(word: &str) -> Vec<(String, Form)> {
+    let word = word.trim().to_lowercase();
+    let mut candidates: Vec<(String, Form)> = Vec::new();
+    let mut push = |lemma: String, form: Form| {
+        if lemma.chars().count() > 1
+            && lemma != word
+            && !candidates.iter().any(|(l, _)| *l == lemma)
+        {
+            candidates.push((lemma, form));
+        }
+    };
+    for &(inflected, lemma) in IRREGULAR {
+        if inflected == word {
+            push(lemma.to_owned(), Form::Irregular);
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ies") {
+        push(format!("{}y", stem), Form::Suffix("-ies"));
+    }
+    if let Some(stem) = word.strip_suffix("es") {
+        if ["s", "x", "z", "ch", "sh", "o"]
+            .iter()
+            .any(|end| stem.ends_with(end))
+        {
+            push(stem.to_owned(), Form::Suffix("-es"));
+        }
+    }
+    if let Some(stem) = word.strip_suffix('s') {
+        if !stem.ends_with('s') {
+            push(stem.to_owned(), Form::Suffix("-s"));
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ied") {
+        push(format!("{}y", stem), Form::Suffix("-ed"));
+    }
+    for &suffix in &["ing", "ed", "est", "er"] {
+        if let Some(stem) = word.strip_suffix(suffix) {
+            let form = Form::Suffix(match suffix {
+                "ing" => "-ing",
+                "ed" => "-ed",
+                "est" => "-est",
+                _ => "-er",
+            });
+            if let Some(single) = undoubled(stem) {
+                push(single, form);
+            }
+            if suffix != "ing" {
+                if let Some(stem) = stem.strip_suffix('i') {
+                    push(format!("{}y", stem), form);
+                }
+            }
+            push(stem.to_owned(), form);
+            push(format!("{}e", stem), form);
+        }
+    }
+    if let Some(stem) = word.strip_suffix("ily") {
+        push(format!("{}y", stem), Form::Suffix("-ly"));
+    }
+    if let Some(stem) = word.strip_suffix("ly") {
+        push(stem.to_owned(), Form::Suffix("-ly"));
+    }
+    candidates
+}
+
+/// `stopp` to `stop`, for stems whose final consonant was doubled.
+fn undoubled(stem: &str) -> Option<String> {
+    let mut chars = stem.chars().rev();
+    let last = chars.next()?;
+    let before = chars.next()?;
+    if last == before && !"aeiouslz".contains(last) {
+        Some(stem[..stem.len() - last.len_utf8()].to_owned())
+    } else {
+        None
+    }
+}
+
+/// The forms listed in the `derivation` fields of `word`, once each.
+pub fn related_forms(word: &Word) -> Vec<String> {
+    let mut seen = HashSet::new();
+    word.entries
+        .iter()
+        .flat_map(|entry| entry.derivation.iter().flatten())
+        .filter(|form| **form != word.word && seen.insert(form.as_str()))
+        .cloned()
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct LemmaMatch {
+    /// The word that was asked for.
+    pub requested: String,
+    /// The form the result is for.
+    pub lemma: String,
+    pub form: Form,
+    pub word: Word,
+    /// Forms linked to the result through its `derivation` fields.
+    pub related: Vec<String>,
+}
+
+/// Looks up a word, falling back to its lemmas when the API does not know
+/// it or has little on it.
+///
+/// ```no_run
+/// use wordsapi::morphology::Lemmatizer;
+/// use wordsapi::Client;
+///
+/// let client = Client::new("TOKEN");
+/// let found = Lemmatizer::new(&client).look_up("geese").unwrap();
+/// // goose (Irregular)
+/// println!("{} ({:?})", found.lemma, found.form);
+/// ```
+pub struct Lemmatizer<'a, L: Lookup> {
+    lookup: &'a L,
+    min_entries: usize,
+}
+
+impl<'a, L: Lookup> Lemmatizer<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        Lemmatizer {
+            lookup,
+            min_entries: 2,
+        }
+    }
+
+    /// A word with fewer entries than this counts as thin, and its lemmas are
+    /// tried as well. Defaults to 2.
+    pub fn min_entries(mut self, min_entries: usize) -> Self {
+        self.min_entries = min_entries;
+        self
+    }
+
+    /// The requested word if it has enough entries, otherwise the first
+    /// lemma from `candidate_lemmas` with more entries than it.
+    pub fn look_up(&self, word: &str) -> Result<LemmaMatch, RequestError> {
+        let exact = self.fetch(word)?;
+        let have = exact.as_ref().map_or(0, |exact| exact.entries.len());
+        if have >= self.min_entries {
+            if let Some(exact) = exact {
+                return Ok(matched(word, Form::Exact, exact));
+            }
+        }
+        for (lemma, form) in candidate_lemmas(word) {
+            if let Some(found) = self.fetch(&lemma)? {
+                if found.entries.len() > have {
+                    return Ok(matched(word, form, found));
+                }
+            }
+        }
+        match exact {
+            Some(exact) => Ok(matched(word, Form::Exact, exact)),
+            None => Err(RequestError::NotFound {
+                word: word.to_owned(),
+            }),
+        }
+    }
+
+    fn fetch(&self, word: &str) -> Result<Option<Word>, RequestError> {
+        match self.lookup.look_up_parsed::<Word>(word) {
+            Ok(word) => Ok(Some(word)),
+            Err(RequestError::NotFound { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn matched(requested: &str, form: Form, word: Word) -> LemmaMatch {
+    LemmaMatch {
+        requested: requested.to_owned(),
+        lemma: word.word.clone(),
+        form,
+        related: related_forms(&word),
+        word,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lookup::fake::FakeLookup;
+    use crate::morphology::{candidate_lemmas, Form, Lemmatizer};
+    use crate::RequestError;
+
+    fn lemmas(word: &str) -> Vec<String> {
+        candidate_lemmas(word)
+            .into_iter()
+            .map(|(lemma, _)| lemma)
+            .collect()
+    }
+
+    #[test]
+    fn it_derives_candidate_lemmas() {
+        assert_eq!(lemmas("geese"), vec!["goose"]);
+        assert!(lemmas("running").starts_with(&["run".to_owned()]));
+        assert!(lemmas("making").contains(&"make".to_owned()));
+        assert!(lemmas("cities").starts_with(&["city".to_owned()]));
+        assert!(lemmas("boxes").contains(&"box".to_owned()));
+        assert!(lemmas("happier").contains(&"happy".to_owned()));
+        assert!(lemmas("carried").contains(&"carry".to_owned()));
+        assert!(lemmas("quickly").contains(&"quick".to_owned()));
+        assert!(lemmas("glass").is_empty());
+    }
+
+    fn lookup() -> FakeLookup {
+        FakeLookup::new()
+            .word(
+                r#"{"word": "goose", "results": [
+                {"definition": "web-footed bird", "derivation": ["gosling"]},
+                {"definition": "a silly person"}
+            ]}"#,
+            )
+            .word(r#"{"word": "running", "results": [{"definition": "the act of running"}]}"#)
+            .word(
+                r#"{"word": "run", "results": [
+                {"definition": "move fast using one's feet", "derivation": ["runner", "running"]},
+                {"definition": "a score in baseball"},
+                {"definition": "a race"}
+            ]}"#,
+            )
+    }
+
+    #[test]
+    fn it_falls_back_to_lemmas() {
+        let lookup = lookup();
+        let lemmatizer = Lemmatizer::new(&lookup);
+        let geese = lemmatizer.look_up("geese").unwrap();
+        assert_eq!(
+            (geese.lemma.as_str(), geese.form),
+            ("goose", Form::Irregular)
+        );
+        assert_eq!(geese.related, vec!["gosling"]);
+
+        let running = lemmatizer.look_up("running").unwrap();
+        assert_eq!(
+            (running.lemma.as_str(), running.form),
+            ("run", Form::Suffix("-ing"))
+        );
+        assert_eq!(running.related, vec!["runner", "running"]);
+    }
+
+    #[test]
+    fn it_keeps_rich_and_unmatched_words() {
+        let lookup = lookup();
+        let run = Lemmatizer::new(&lookup).look_up("run").unwrap();
+        assert_eq!(run.form, Form::Exact);
+        let running = Lemmatizer::new(&lookup)
+            .min_entries(1)
+            .look_up("running")
+            .unwrap();
+        assert_eq!(
+            (running.lemma.as_str(), running.form),
+            ("running", Form::Exact)
+        );
+        assert!(matches!(
+            Lemmatizer::new(&lookup).look_up("zzzs"),
+            Err(RequestError::NotFound { .. })
+        ));
+    }
+}
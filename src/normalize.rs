@@ -0,0 +1,159 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::RequestError;
+
+/// The longest word, in characters, that will be sent to the API.
+pub const MAX_WORD_LENGTH: usize = 100;
+
+/// Clean-up applied to a word before it is looked up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Normalization {
+    pub trim: bool,
+    /// Compose characters into Unicode Normalization Form C, so "café" is
+    /// requested the same way whether it was typed with a combining accent
+    /// or not.
+    pub nfc: bool,
+    pub lowercase: bool,
+    /// Replace every run of whitespace with a single space.
+    pub collapse_whitespace: bool,
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self {
+            trim: true,
+            nfc: true,
+            lowercase: false,
+            collapse_whitespace: true,
+        }
+    }
+}
+
+impl Normalization {
+    pub fn apply(&self, word: &str) -> String {
+        let mut normalized = if self.trim {
+            word.trim().to_owned()
+        } else {
+            word.to_owned()
+        };
+        if self.nfc {
+            normalized = normalized.nfc().collect();
+        }
+        if self.lowercase {
+            normalized = normalized.to_lowercase();
+        }
+        if self.collapse_whitespace {
+            normalized = collapse_whitespace(&normalized);
+        }
+        normalized
+    }
+}
+
+fn collapse_whitespace(word: &str) -> String {
+    let mut collapsed = String::with_capacity(word.len());
+    let mut in_whitespace = false;
+    for c in word.chars() {
+        if c.is_whitespace() {
+            if !in_whitespace {
+                collapsed.push(' ');
+            }
+            in_whitespace = true;
+        } else {
+            collapsed.push(c);
+            in_whitespace = false;
+        }
+    }
+    collapsed
+}
+
+/// Rejects words that cannot be a valid request, before one is spent on them.
+pub fn validate(word: &str) -> Result<(), RequestError> {
+    let reason = if word.trim().is_empty() {
+        "is empty"
+    } else if word.chars().count() > MAX_WORD_LENGTH {
+        "is too long"
+    } else if word == "." || word == ".." {
+        // Sent as a path segment, these would resolve to another endpoint.
+        "is a dot segment"
+    } else {
+        return Ok(());
+    };
+    Err(RequestError::InvalidWord {
+        word: word.to_owned(),
+        reason,
+    })
+}
+
+/// Percent-encodes `segment` for use as a URL path segment or query value.
+pub(crate) fn percent_encode(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::normalize::percent_encode;
+    use crate::normalize::validate;
+    use crate::normalize::MAX_WORD_LENGTH;
+    use crate::Normalization;
+
+    #[test]
+    fn it_trims_and_collapses_whitespace() {
+        let normalization = Normalization::default();
+        assert_eq!(normalization.apply("  ice \t cream\n"), "ice cream");
+    }
+
+    #[test]
+    fn it_composes_to_nfc() {
+        let normalization = Normalization::default();
+        assert_eq!(normalization.apply("cafe\u{301}"), "caf\u{e9}");
+    }
+
+    #[test]
+    fn it_lowercases_when_asked() {
+        let normalization = Normalization {
+            lowercase: true,
+            ..Normalization::default()
+        };
+        assert_eq!(normalization.apply("Paris"), "paris");
+        assert_eq!(Normalization::default().apply("Paris"), "Paris");
+    }
+
+    #[test]
+    fn it_rejects_empty_words() {
+        assert!(validate("").is_err());
+        assert!(validate(" \t").is_err());
+        assert!(validate("a").is_ok());
+    }
+
+    #[test]
+    fn it_rejects_long_words() {
+        assert!(validate(&"a".repeat(MAX_WORD_LENGTH)).is_ok());
+        assert!(validate(&"a".repeat(MAX_WORD_LENGTH + 1)).is_err());
+    }
+
+    #[test]
+    fn it_rejects_dot_segments() {
+        assert!(validate(".").is_err());
+        assert!(validate("..").is_err());
+        assert!(validate("...").is_ok());
+        assert!(validate("a.m.").is_ok());
+    }
+
+    #[test]
+    fn it_percent_encodes() {
+        assert_eq!(percent_encode("ice cream"), "ice%20cream");
+        assert_eq!(percent_encode("o'clock"), "o%27clock");
+        assert_eq!(percent_encode("café"), "caf%C3%A9");
+        assert_eq!(percent_encode("and/or"), "and%2For");
+        assert_eq!(percent_encode("well-being"), "well-being");
+    }
+}
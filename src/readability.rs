@@ -0,0 +1,313 @@
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+use crate::lookup::WordMemo;
+use crate::Difficulty;
+use crate::Lookup;
+use crate::RequestError;
+use crate::Word;
+
+/// The highest Zipf frequency; words at or above it count as trivial.
+const MAX_ZIPF: f64 = 7.0;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadabilityReport {
+    pub sentences: usize,
+    pub words: usize,
+    pub distinct_words: usize,
+    pub syllables: usize,
+    /// Words of three or more syllables.
+    pub polysyllables: usize,
+    /// Distinct words whose syllables were estimated from their spelling
+    /// because the API has no count for them.
+    pub estimated: Vec<String>,
+    /// Flesch-Kincaid grade level.
+    pub flesch_kincaid: f64,
+    /// SMOG grade, meant for passages of 30 sentences or more.
+    pub smog: f64,
+    /// From 0 for a passage of only the most common words to 1 for one of
+    /// only unknown words, weighting each word by how rare it is.
+    pub difficulty: f64,
+    /// The rarest words that are not common, rarest first.
+    pub rare_words: Vec<RareWord>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RareWord {
+    pub word: String,
+    /// `None` if the API has no frequency for the word.
+    pub zipf: Option<f32>,
+    pub definition: Option<String>,
+}
+
+/// Scores passages using syllable counts and frequencies from the API.
+///
+/// Each distinct word of a passage is looked up once, before scoring, and
+/// remembered for later passages. Giving the client a `Cache` keeps the
+/// lookups across runs as well. The lookups are made one after another:
+/// the API has no batch endpoint, and `Client` is blocking.
+///
+/// ```no_run
+/// use wordsapi::readability::Readability;
+/// use wordsapi::{Cache, Client};
+///
+/// let client = Client::new("TOKEN").with_cache(Cache::new());
+/// let report = Readability::new(&client)
+///     .analyze("The quick brown fox jumps over the lazy dog.")
+///     .unwrap();
+/// println!("grade {:.1}", report.flesch_kincaid);
+/// ```
+pub struct Readability<'a, L: Lookup> {
+    rare_words: usize,
+    words: WordMemo<'a, L>,
+}
+
+impl<'a, L: Lookup> Readability<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        Self {
+            rare_words: 5,
+            words: WordMemo::new(lookup),
+        }
+    }
+
+    /// How many rare words to flag in a report. Defaults to 5.
+    pub fn rare_words(mut self, count: usize) -> Self {
+        self.rare_words = count;
+        self
+    }
+
+    pub fn analyze(&self, text: &str) -> Result<ReadabilityReport, RequestError> {
+        let tokens = words(text);
+        let mut seen = HashSet::new();
+        let mut distinct: Vec<&str> = Vec::new();
+        for token in &tokens {
+            if seen.insert(token.as_str()) {
+                distinct.push(token);
+            }
+        }
+        let mut looked_up = HashMap::new();
+        for word in &distinct {
+            // A token the API would refuse, such as an overlong hyphenated
+            // compound, is scored like an unknown word.
+            let found = match self.words.word(word) {
+                Ok(found) => found,
+                Err(RequestError::InvalidWord { .. }) => None,
+                Err(e) => return Err(e),
+            };
+            looked_up.insert(*word, found);
+        }
+
+        let mut estimated = Vec::new();
+        let mut syllable_counts = HashMap::new();
+        for word in &distinct {
+            let count = match looked_up[word].as_ref().and_then(|w| w.syllables.as_ref()) {
+                Some(syllables) => syllables.count as usize,
+                None => {
+                    estimated.push((*word).to_owned());
+                    estimate_syllables(word)
+                }
+            };
+            syllable_counts.insert(*word, count);
+        }
+
+        let sentences = sentences(text).max(if tokens.is_empty() { 0 } else { 1 });
+        let syllables: usize = tokens.iter().map(|t| syllable_counts[t.as_str()]).sum();
+        let polysyllables = tokens
+            .iter()
+            .filter(|t| syllable_counts[t.as_str()] >= 3)
+            .count();
+        let rarity: f64 = tokens
+            .iter()
+            .map(|t| rarity(zipf(&looked_up[t.as_str()])))
+            .sum();
+
+        let mut rare_words: Vec<RareWord> = distinct
+            .iter()
+            .map(|word| {
+                let found = &looked_up[word];
+                RareWord {
+                    word: (*word).to_owned(),
+                    zipf: zipf(found),
+                    definition: found
+                        .as_ref()
+                        .and_then(|w| w.entries.first())
+                        .map(|entry| entry.definition.clone()),
+                }
+            })
+            .filter(|rare| {
+                rare.zipf.map_or(Difficulty::Rare, Difficulty::from_zipf) != Difficulty::Common
+            })
+            .collect();
+        rare_words.sort_by(|a, b| {
+            let a = a.zipf.unwrap_or(f32::NEG_INFINITY);
+            let b = b.zipf.unwrap_or(f32::NEG_INFINITY);
+            a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        rare_words.truncate(self.rare_words);
+
+        let (flesch_kincaid, smog, difficulty) = if tokens.is_empty() {
+            (0.0, 0.0, 0.0)
+        } else {
+            let words = tokens.len() as f64;
+            let sentences = sentences as f64;
+            (
+                0.39 * words / sentences + 11.8 * syllables as f64 / words - 15.59,
+                1.043 * (polysyllables as f64 * 30.0 / sentences).sqrt() + 3.1291,
+                rarity / words,
+            )
+        };
+        Ok(ReadabilityReport {
+            sentences,
+            words: tokens.len(),
+            distinct_words: distinct.len(),
+            syllables,
+            polysyllables,
+            estimated,
+            flesch_kincaid,
+            smog,
+            difficulty,
+            rare_words,
+        })
+    }
+}
+
+fn zipf(word: &Option<Rc<Word>>) -> Option<f32> {
+    word.as_ref().and_then(|w| w.frequency)
+}
+
+/// 0 for the most frequent words, 1 for the rarest and for unknown ones.
+fn rarity(zipf: Option<f32>) -> f64 {
+    match zipf {
+        Some(zipf) => (1.0 - f64::from(zipf) / MAX_ZIPF).clamp(0.0, 1.0),
+        None => 1.0,
+    }
+}
+
+/// The lowercased words of `text`. Apostrophes and hyphens inside a word are
+/// kept, and tokens with digits are skipped.
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '’' || c == '-'))
+        .map(|token| token.trim_matches(|c| c == '\'' || c == '’' || c == '-'))
+        .filter(|token| !token.is_empty() && token.chars().all(|c| !c.is_numeric()))
+        .map(|token| token.replace('’', "'").to_lowercase())
+        .collect()
+}
+
+fn sentences(text: &str) -> usize {
+    text.split(['.', '!', '?'])
+        .filter(|sentence| sentence.chars().any(char::is_alphabetic))
+        .count()
+}
+
+/// Counts groups of vowels, not counting a silent final `e`.
+fn estimate_syllables(word: &str) -> usize {
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = "aeiouy".contains(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    if count > 1 && word.ends_with('e') && !word.ends_with("le") {
+        count -= 1;
+    }
+    count.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lookup::fake::FakeLookup;
+    use crate::readability::{estimate_syllables, words, Readability};
+
+    fn word(name: &str, frequency: f32, syllables: u32, definition: &str) -> String {
+        format!(
+            r#"{{"word": "{}", "frequency": {}, "syllables": {{"count": {}, "list": []}},
+                "results": [{{"definition": "{}"}}]}}"#,
+            name, frequency, syllables, definition
+        )
+    }
+
+    fn lookup() -> FakeLookup {
+        FakeLookup::new()
+            .word(&word("the", 7.4, 1, "the definite article"))
+            .word(&word("cat", 4.8, 1, "a small feline"))
+            .word(&word("sat", 4.5, 1, "past tense of sit"))
+            .word(&word("on", 7.0, 1, "in contact with"))
+            .word(&word("mat", 3.6, 1, "a floor covering"))
+            .word(&word("obstreperous", 1.6, 4, "noisily defiant"))
+    }
+
+    #[test]
+    fn it_tokenizes_words() {
+        assert_eq!(
+            words("Don't stop—the well-known 42 cats' 'toys'!"),
+            vec!["don't", "stop", "the", "well-known", "cats", "toys"]
+        );
+    }
+
+    #[test]
+    fn it_estimates_syllables() {
+        assert_eq!(estimate_syllables("cake"), 1);
+        assert_eq!(estimate_syllables("table"), 2);
+        assert_eq!(estimate_syllables("beautiful"), 3);
+        assert_eq!(estimate_syllables("rhythm"), 1);
+    }
+
+    #[test]
+    fn it_scores_a_passage() {
+        let lookup = lookup();
+        let report = Readability::new(&lookup)
+            .rare_words(2)
+            .analyze("The cat sat on the mat. The obstreperous cat sat on Zorblax!")
+            .unwrap();
+        assert_eq!(report.sentences, 2);
+        assert_eq!(report.words, 12);
+        assert_eq!(report.distinct_words, 7);
+        assert_eq!(report.syllables, 16);
+        assert_eq!(report.polysyllables, 1);
+        assert_eq!(report.estimated, vec!["zorblax"]);
+        let grade = 0.39 * 12.0 / 2.0 + 11.8 * 16.0 / 12.0 - 15.59;
+        assert!((report.flesch_kincaid - grade).abs() < 1e-9);
+        assert!(report.difficulty > 0.0 && report.difficulty < 1.0);
+        let rare: Vec<&str> = report.rare_words.iter().map(|r| r.word.as_str()).collect();
+        assert_eq!(rare, vec!["zorblax", "obstreperous"]);
+        assert_eq!(
+            report.rare_words[1].definition.as_deref(),
+            Some("noisily defiant")
+        );
+        assert_eq!(lookup.fetches.get(), 7);
+    }
+
+    #[test]
+    fn it_reuses_lookups_across_passages() {
+        let lookup = lookup();
+        let readability = Readability::new(&lookup);
+        readability.analyze("The cat sat.").unwrap();
+        readability.analyze("The cat sat on the mat.").unwrap();
+        assert_eq!(lookup.fetches.get(), 5);
+    }
+
+    #[test]
+    fn it_estimates_words_the_api_would_refuse() {
+        let lookup = lookup();
+        let long = vec!["cat"; 30].join("-");
+        let report = Readability::new(&lookup)
+            .analyze(&format!("The {} sat.", long))
+            .unwrap();
+        assert_eq!(report.words, 3);
+        assert_eq!(report.estimated, vec![long.clone()]);
+        assert_eq!(report.rare_words[0].word, long);
+        assert_eq!(report.rare_words[0].zipf, None);
+    }
+
+    #[test]
+    fn it_scores_empty_text_as_zero() {
+        let lookup = lookup();
+        let report = Readability::new(&lookup).analyze("  ... ").unwrap();
+        assert_eq!(report.words, 0);
+        assert_eq!(report.sentences, 0);
+        assert_eq!(report.flesch_kincaid, 0.0);
+    }
+}
@@ -1,4 +1,8 @@
-#[derive(Debug)]
+use core::fmt;
+use core::str::FromStr;
+use std::error::Error as StdError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RequestType {
     Word,
     Definitions,
@@ -30,6 +34,127 @@ pub enum RequestType {
     Entails,
 }
 
+impl RequestType {
+    pub const ALL: [RequestType; 28] = [
+        RequestType::Word,
+        RequestType::Definitions,
+        RequestType::Synonyms,
+        RequestType::Antonyms,
+        RequestType::Examples,
+        RequestType::Rhymes,
+        RequestType::Frequency,
+        RequestType::IsATypeOf,
+        RequestType::HasTypes,
+        RequestType::PartOf,
+        RequestType::HasParts,
+        RequestType::IsAnInstanceOf,
+        RequestType::HasInstances,
+        RequestType::InRegion,
+        RequestType::RegionOf,
+        RequestType::UsageOf,
+        RequestType::HasUsages,
+        RequestType::IsAMemberOf,
+        RequestType::HasMembers,
+        RequestType::IsASubstanceOf,
+        RequestType::HasSubstances,
+        RequestType::HasAttribute,
+        RequestType::InCategory,
+        RequestType::HasCategories,
+        RequestType::Also,
+        RequestType::PertainsTo,
+        RequestType::SimilarTo,
+        RequestType::Entails,
+    ];
+
+    /// The API's name for this request type, as used in request URLs.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            RequestType::Word => "word",
+            RequestType::Definitions => "definitions",
+            RequestType::Synonyms => "synonyms",
+            RequestType::Antonyms => "antonyms",
+            RequestType::Examples => "examples",
+            RequestType::Rhymes => "rhymes",
+            RequestType::Frequency => "frequency",
+            RequestType::IsATypeOf => "isATypeOf",
+            RequestType::HasTypes => "hasTypes",
+            RequestType::PartOf => "partOf",
+            RequestType::HasParts => "hasParts",
+            RequestType::IsAnInstanceOf => "isAnInstanceOf",
+            RequestType::HasInstances => "hasInstances",
+            RequestType::InRegion => "inRegion",
+            RequestType::RegionOf => "regionOf",
+            RequestType::UsageOf => "usageOf",
+            RequestType::HasUsages => "hasUsages",
+            RequestType::IsAMemberOf => "isAMemberOf",
+            RequestType::HasMembers => "hasMembers",
+            RequestType::IsASubstanceOf => "isASubstanceOf",
+            RequestType::HasSubstances => "hasSubstances",
+            RequestType::HasAttribute => "hasAttribute",
+            RequestType::InCategory => "inCategory",
+            RequestType::HasCategories => "hasCategories",
+            RequestType::Also => "also",
+            RequestType::PertainsTo => "pertainsTo",
+            RequestType::SimilarTo => "similarTo",
+            RequestType::Entails => "entails",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct UnknownRequestType(pub String);
+
+impl fmt::Display for UnknownRequestType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "UnknownRequestType: {}", self.0)
+    }
+}
+
+impl StdError for UnknownRequestType {}
+
+/// Accepts the API's name (`isATypeOf`) as well as kebab-case (`is-a-type-of`),
+/// ignoring case.
+impl FromStr for RequestType {
+    type Err = UnknownRequestType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let wanted = s.replace('-', "").to_lowercase();
+        RequestType::ALL
+            .iter()
+            .find(|request_type| request_type.name().to_lowercase() == wanted)
+            .cloned()
+            .ok_or_else(|| UnknownRequestType(s.to_owned()))
+    }
+}
+
 pub trait HasRequestType {
     fn request_type() -> RequestType;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::RequestType;
+
+    #[test]
+    fn it_parses_api_names() {
+        for request_type in RequestType::ALL.iter() {
+            assert_eq!(
+                request_type.name().parse::<RequestType>().unwrap(),
+                *request_type
+            );
+        }
+    }
+
+    #[test]
+    fn it_parses_kebab_case() {
+        assert_eq!(
+            "is-a-type-of".parse::<RequestType>().unwrap(),
+            RequestType::IsATypeOf
+        );
+        assert_eq!(
+            "Synonyms".parse::<RequestType>().unwrap(),
+            RequestType::Synonyms
+        );
+        assert!("synonym".parse::<RequestType>().is_err());
+    }
+}
@@ -1,22 +1,64 @@
-use log::trace;
-use serde::de::DeserializeOwned;
+use core::fmt;
+use std::time::Duration;
+
+use hyper::HeaderMap;
+
+use log::{trace, warn};
+use serde::de::{Deserialize, DeserializeOwned};
+use serde_json::Value;
+use serde_path_to_error::Segment;
 
 use crate::RequestError;
 
 pub struct Response<T> {
     pub result: Result<T, RequestError>,
     pub response_json: String,
+    /// Zero for a response from the cache, which made no request.
     pub rate_limit_remaining: usize,
     pub rate_limit_requests_limit: usize,
+    pub warnings: Vec<ParseWarning>,
+    /// The HTTP status code, or 0 if the response did not come from a `Client`.
+    pub status: u16,
+    pub headers: HeaderMap,
+    /// The URL that was requested. The API key is sent as a header, so it
+    /// never appears here.
+    pub request_url: String,
+    pub elapsed: Duration,
+    /// How many times the request was retried before this response.
+    pub retries: u32,
+    pub from_cache: bool,
 }
 
 impl<T: DeserializeOwned> Response<T> {
     pub fn new(raw_json: String, allowed: usize, remaining: usize) -> Self {
+        let result = try_parse::<T>(&raw_json);
+        Self::from_parts(result, Vec::new(), raw_json, allowed, remaining)
+    }
+
+    pub fn new_lenient(raw_json: String, allowed: usize, remaining: usize) -> Self {
+        let (result, warnings) = try_parse_lenient::<T>(&raw_json);
+        Self::from_parts(result, warnings, raw_json, allowed, remaining)
+    }
+
+    fn from_parts(
+        result: Result<T, RequestError>,
+        warnings: Vec<ParseWarning>,
+        raw_json: String,
+        allowed: usize,
+        remaining: usize,
+    ) -> Self {
         Self {
-            result: try_parse::<T>(&raw_json),
+            result,
             response_json: raw_json,
             rate_limit_remaining: remaining,
             rate_limit_requests_limit: allowed,
+            warnings,
+            status: 0,
+            headers: HeaderMap::new(),
+            request_url: String::new(),
+            elapsed: Duration::default(),
+            retries: 0,
+            from_cache: false,
         }
     }
 
@@ -25,6 +67,42 @@ impl<T: DeserializeOwned> Response<T> {
     }
 }
 
+impl<T> Response<T> {
+    /// Parses the raw JSON into a type that borrows from this response, such
+    /// as `WordRef`.
+    pub fn try_parse_borrowed<'a, B: Deserialize<'a>>(&'a self) -> Result<B, RequestError> {
+        try_parse_borrowed::<B>(&self.response_json)
+    }
+}
+
+/// A field that was dropped while parsing in lenient mode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseWarning {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Deserialize)]
+struct ApiMessage {
+    success: bool,
+    message: String,
+}
+
+/// Whether `body` is the API's `{"success":false,"message":"word not found"}`
+/// reply for a word it does not know.
+pub(crate) fn is_word_not_found(body: &str) -> bool {
+    match serde_json::from_str::<ApiMessage>(body) {
+        Ok(reply) => !reply.success && reply.message.eq_ignore_ascii_case("word not found"),
+        Err(_) => false,
+    }
+}
+
 pub fn try_parse<T: DeserializeOwned>(word_json: &str) -> Result<T, RequestError> {
     let result: Result<T, serde_json::Error> = serde_json::from_str::<T>(word_json);
     match result {
@@ -35,3 +113,257 @@ pub fn try_parse<T: DeserializeOwned>(word_json: &str) -> Result<T, RequestError
         }
     }
 }
+
+pub fn try_parse_borrowed<'a, T: Deserialize<'a>>(word_json: &'a str) -> Result<T, RequestError> {
+    serde_json::from_str::<T>(word_json).map_err(|e| {
+        trace!("serde says {}", e);
+        RequestError::ResultParseError
+    })
+}
+
+/// Parses `word_json`, dropping values that do not fit `T` instead of failing.
+///
+/// A field with the wrong type is removed, and a list element that is still
+/// unusable after that (say, an entry whose definition was removed) is removed
+/// as well. Every removal is reported as a `ParseWarning`. Parsing only fails
+/// when the document is not JSON or the top-level value itself is unusable.
+pub fn try_parse_lenient<T: DeserializeOwned>(
+    word_json: &str,
+) -> (Result<T, RequestError>, Vec<ParseWarning>) {
+    let mut warnings = Vec::new();
+    let mut value: Value = match serde_json::from_str(word_json) {
+        Ok(value) => value,
+        Err(e) => {
+            trace!("serde says {}", e);
+            return (Err(RequestError::ResultParseError), warnings);
+        }
+    };
+    loop {
+        let error = match serde_path_to_error::deserialize::<_, T>(&value) {
+            Ok(parsed) => return (Ok(parsed), warnings),
+            Err(error) => error,
+        };
+        let path = error.path().to_string();
+        let segments: Vec<&Segment> = error.path().iter().collect();
+        if !remove_at(&mut value, &segments) {
+            trace!("serde says {} at {}", error.inner(), path);
+            return (Err(RequestError::ResultParseError), warnings);
+        }
+        warn!("dropped {} while parsing: {}", path, error.inner());
+        warnings.push(ParseWarning {
+            path,
+            message: error.inner().to_string(),
+        });
+    }
+}
+
+fn remove_at(value: &mut Value, segments: &[&Segment]) -> bool {
+    let (last, parents) = match segments.split_last() {
+        Some(split) => split,
+        None => return false,
+    };
+    let mut parent = value;
+    for segment in parents {
+        parent = match (segment, parent) {
+            (Segment::Seq { index }, Value::Array(items)) => match items.get_mut(*index) {
+                Some(item) => item,
+                None => return false,
+            },
+            (Segment::Map { key }, Value::Object(fields)) => match fields.get_mut(key) {
+                Some(field) => field,
+                None => return false,
+            },
+            _ => return false,
+        };
+    }
+    match (last, parent) {
+        (Segment::Seq { index }, Value::Array(items)) if *index < items.len() => {
+            items.remove(*index);
+            true
+        }
+        (Segment::Map { key }, Value::Object(fields)) => fields.remove(key).is_some(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::response::is_word_not_found;
+    use crate::response::try_parse_lenient;
+    use crate::try_parse;
+    use crate::try_parse_borrowed;
+    use crate::ParseWarning;
+    use crate::RequestError;
+    use crate::Response;
+    use crate::Word;
+    use crate::WordRef;
+    use std::borrow::Cow;
+    use std::time::Duration;
+
+    static DRIFTED_JSON: &str = r#"{
+        "word": "example",
+        "frequency": "often",
+        "letters": {"count": 7},
+        "results": [
+            {"definition": "an item of information", "partOfSpeech": "noun", "synonyms": "case"},
+            {"definition": 42, "partOfSpeech": "noun"},
+            {"definition": "a representative form", "partOfSpeech": "noun", "regionOf": ["x"]}
+        ]
+    }"#;
+
+    #[test]
+    fn it_fills_in_response_parts() {
+        let response = Response::<Word>::from_parts(
+            Err(RequestError::ResultParseError),
+            vec![ParseWarning {
+                path: "frequency".to_owned(),
+                message: "invalid type".to_owned(),
+            }],
+            "{}".to_owned(),
+            2500,
+            2499,
+        );
+        assert!(response.result.is_err());
+        assert_eq!(response.response_json, "{}");
+        assert_eq!(response.rate_limit_requests_limit, 2500);
+        assert_eq!(response.rate_limit_remaining, 2499);
+        assert_eq!(response.warnings.len(), 1);
+        assert_eq!(response.warnings[0].path, "frequency");
+    }
+
+    #[test]
+    fn it_defaults_response_metadata() {
+        let response = Response::<Word>::from_parts(
+            Err(RequestError::ResultParseError),
+            Vec::new(),
+            String::new(),
+            0,
+            0,
+        );
+        assert_eq!(response.status, 0);
+        assert!(response.headers.is_empty());
+        assert_eq!(response.request_url, "");
+        assert_eq!(response.elapsed, Duration::default());
+        assert_eq!(response.retries, 0);
+        assert!(!response.from_cache);
+        assert!(response.warnings.is_empty());
+    }
+
+    #[test]
+    fn it_collects_warnings_in_lenient_responses() {
+        let response = Response::<Word>::new_lenient(DRIFTED_JSON.to_owned(), 2500, 2499);
+        assert!(response.result.is_ok());
+        assert_eq!(response.warnings.len(), 4);
+        let strict = Response::<Word>::new(DRIFTED_JSON.to_owned(), 2500, 2499);
+        assert!(strict.result.is_err());
+        assert!(strict.warnings.is_empty());
+    }
+
+    #[test]
+    fn it_fails_strict_parse_on_drift() {
+        assert!(try_parse::<Word>(DRIFTED_JSON).is_err());
+    }
+
+    #[test]
+    fn it_drops_mistyped_fields_leniently() {
+        let (result, warnings) = try_parse_lenient::<Word>(DRIFTED_JSON);
+        let word = result.unwrap();
+        assert_eq!(word.frequency, None);
+        assert_eq!(word.entries.len(), 2);
+        assert_eq!(word.entries[0].synonyms, None);
+        assert_eq!(word.entries[1].definition, "a representative form");
+        let paths: Vec<&str> = warnings.iter().map(|w| w.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "frequency",
+                "results[0].synonyms",
+                "results[1].definition",
+                "results[1]"
+            ]
+        );
+    }
+
+    #[test]
+    fn it_keeps_unknown_fields() {
+        let (result, _) = try_parse_lenient::<Word>(DRIFTED_JSON);
+        let word = result.unwrap();
+        assert!(word.extra.contains_key("letters"));
+        assert!(word.entries[1].extra.contains_key("regionOf"));
+    }
+
+    #[test]
+    fn it_fails_lenient_parse_without_required_root_fields() {
+        let (result, _) = try_parse_lenient::<Word>(r#"{"word": 7, "results": []}"#);
+        assert!(result.is_err());
+    }
+
+    static WORD_JSON: &str = r#"{
+        "word": "example",
+        "frequency": 4.5,
+        "pronunciation": {"all": "ɪɡ'zæmpəl"},
+        "results": [
+            {"definition": "a \"typical\" item", "partOfSpeech": "noun", "synonyms": ["case", "instance"]}
+        ]
+    }"#;
+
+    #[test]
+    fn it_borrows_strings_without_escapes() {
+        let word = try_parse_borrowed::<WordRef>(WORD_JSON).unwrap();
+        assert!(match word.word {
+            Cow::Borrowed(w) => w == "example",
+            Cow::Owned(_) => false,
+        });
+        assert!(match word.entries[0].definition {
+            Cow::Owned(ref d) => d == "a \"typical\" item",
+            Cow::Borrowed(_) => false,
+        });
+        let synonyms = word.entries[0].synonyms.as_ref().unwrap();
+        assert!(match synonyms[0] {
+            Cow::Borrowed(s) => s == "case",
+            Cow::Owned(_) => false,
+        });
+    }
+
+    #[test]
+    fn it_converts_borrowed_to_owned() {
+        let borrowed = try_parse_borrowed::<WordRef>(WORD_JSON).unwrap().to_owned();
+        let owned = try_parse::<Word>(WORD_JSON).unwrap();
+        assert_eq!(
+            serde_json::to_value(&borrowed).unwrap(),
+            serde_json::to_value(&owned).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_converts_borrowed_syllables_to_owned() {
+        let json = r#"{
+            "word": "example",
+            "syllables": {"count": 3, "list": ["ex", "am", "ple"]},
+            "results": []
+        }"#;
+        let borrowed = try_parse_borrowed::<WordRef>(json).unwrap();
+        let syllables = borrowed.syllables.as_ref().unwrap();
+        assert!(match syllables.list[1] {
+            Cow::Borrowed(s) => s == "am",
+            Cow::Owned(_) => false,
+        });
+        let owned = try_parse::<Word>(json).unwrap();
+        assert_eq!(borrowed.to_owned().syllables, owned.syllables);
+        assert_eq!(
+            serde_json::to_value(borrowed.to_owned()).unwrap(),
+            serde_json::to_value(&owned).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_recognizes_word_not_found() {
+        assert!(is_word_not_found(
+            r#"{"success":false,"message":"word not found"}"#
+        ));
+        assert!(!is_word_not_found(
+            r#"{"success":false,"message":"Internal Server Error"}"#
+        ));
+        assert!(!is_word_not_found(WORD_JSON));
+    }
+}
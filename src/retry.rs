@@ -0,0 +1,59 @@
+use std::time::Duration;
+
+/// How often a failed request is retried, and how long to wait in between.
+///
+/// Requests are retried after transport errors, `429 Too Many Requests` and
+/// server errors. The wait doubles after every attempt, starting at
+/// `backoff_ms`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    #[serde(default = "default_backoff_ms")]
+    pub backoff_ms: u64,
+}
+
+fn default_backoff_ms() -> u64 {
+    500
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            backoff_ms: default_backoff_ms(),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new(max_retries: u32, backoff: Duration) -> Self {
+        Self {
+            max_retries,
+            backoff_ms: backoff.as_millis() as u64,
+        }
+    }
+
+    /// The wait before retry number `retry`, counting from zero.
+    pub fn delay(&self, retry: u32) -> Duration {
+        Duration::from_millis(self.backoff_ms.saturating_mul(1 << retry.min(16)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RetryPolicy;
+    use std::time::Duration;
+
+    #[test]
+    fn it_does_not_retry_by_default() {
+        assert_eq!(RetryPolicy::default().max_retries, 0);
+    }
+
+    #[test]
+    fn it_doubles_the_delay() {
+        let policy = RetryPolicy::new(3, Duration::from_millis(250));
+        assert_eq!(policy.delay(0), Duration::from_millis(250));
+        assert_eq!(policy.delay(1), Duration::from_millis(500));
+        assert_eq!(policy.delay(2), Duration::from_millis(1000));
+    }
+}
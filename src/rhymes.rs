@@ -0,0 +1,273 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::HasRequestType;
+use crate::Lookup;
+use crate::RequestError;
+use crate::RequestType;
+use crate::Word;
+
+/// The reply to a `RequestType::Rhymes` request.
+///
+/// The API groups rhymes under `all`, or by part of speech for words whose
+/// pronunciation depends on it.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Rhymes {
+    pub word: String,
+    pub rhymes: HashMap<String, Vec<String>>,
+}
+
+impl HasRequestType for Rhymes {
+    fn request_type() -> RequestType {
+        RequestType::Rhymes
+    }
+}
+
+impl Rhymes {
+    /// Every rhyme once, across all groups. The `all` group comes first and
+    /// the others follow by name; each group keeps the API's order.
+    pub fn all(&self) -> Vec<&str> {
+        let mut groups: Vec<&String> = self.rhymes.keys().collect();
+        groups.sort_by_key(|group| (group.as_str() != "all", group.as_str()));
+        let mut seen = HashSet::new();
+        groups
+            .into_iter()
+            .flat_map(|group| &self.rhymes[group])
+            .map(String::as_str)
+            .filter(|rhyme| seen.insert(*rhyme))
+            .collect()
+    }
+
+    /// The rhymes listed for one part of speech.
+    pub fn part_of_speech(&self, part_of_speech: &str) -> &[String] {
+        self.rhymes
+            .get(part_of_speech)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RhymeKind {
+    /// The sounds from the last stressed vowel on are identical.
+    Perfect,
+    /// Only the stressed vowels or only the final consonants agree.
+    Near,
+    /// Both pronunciations are known and do not rhyme.
+    NotARhyme,
+    /// A word has no usable pronunciation to compare.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rhyme {
+    pub word: String,
+    pub kind: RhymeKind,
+    pub syllables: Option<u32>,
+    pub frequency: Option<f32>,
+}
+
+/// Looks up the rhymes of a word along with the pronunciation, syllables and
+/// frequency of each, most frequent first.
+///
+/// Every rhyme costs a `Word` request, so only the first `limit` rhymes the
+/// API returns (50 by default) are looked up.
+pub struct RhymeFinder<'a, L: Lookup> {
+    lookup: &'a L,
+    limit: usize,
+    syllables: Option<u32>,
+}
+
+impl<'a, L: Lookup> RhymeFinder<'a, L> {
+    pub fn new(lookup: &'a L) -> Self {
+        RhymeFinder {
+            lookup,
+            limit: 50,
+            syllables: None,
+        }
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Keeps only rhymes with this many syllables.
+    pub fn syllables(mut self, count: u32) -> Self {
+        self.syllables = Some(count);
+        self
+    }
+
+    pub fn find(&self, word: &str) -> Result<Vec<Rhyme>, RequestError> {
+        let rhymes = self.lookup.look_up_parsed::<Rhymes>(word)?;
+        let target = match self.lookup.look_up_parsed::<Word>(word) {
+            Ok(word) => word.pronunciation_of(None).map(str::to_owned),
+            Err(RequestError::NotFound { .. }) => None,
+            Err(e) => return Err(e),
+        };
+        let mut found = Vec::new();
+        for candidate in rhymes.all().into_iter().take(self.limit) {
+            let details = match self.lookup.look_up_parsed::<Word>(candidate) {
+                Ok(details) => Some(details),
+                Err(RequestError::NotFound { .. }) => None,
+                Err(e) => return Err(e),
+            };
+            let details = details.as_ref();
+            let rhyme = Rhyme {
+                word: candidate.to_owned(),
+                kind: match (&target, details.and_then(|d| d.pronunciation_of(None))) {
+                    (Some(a), Some(b)) => classify(a, b),
+                    _ => RhymeKind::Unknown,
+                },
+                syllables: details.and_then(|d| d.syllables.as_ref()).map(|s| s.count),
+                frequency: details.and_then(|d| d.frequency),
+            };
+            if self.syllables.is_none() || rhyme.syllables == self.syllables {
+                found.push(rhyme);
+            }
+        }
+        rank_by_frequency(&mut found);
+        Ok(found)
+    }
+}
+
+/// Sorts rhymes most frequent first. Rhymes without a frequency go last, and
+/// ties keep their order.
+pub fn rank_by_frequency(rhymes: &mut [Rhyme]) {
+    rhymes.sort_by(|a, b| {
+        let a = a.frequency.unwrap_or(f32::NEG_INFINITY);
+        let b = b.frequency.unwrap_or(f32::NEG_INFINITY);
+        b.partial_cmp(&a).unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+/// Compares two IPA pronunciations, such as `ɡreɪp` and `ə'ʃeɪp`.
+pub fn classify(a: &str, b: &str) -> RhymeKind {
+    let a = rhyming_part(a);
+    let b = rhyming_part(b);
+    if a.is_empty() || b.is_empty() {
+        RhymeKind::Unknown
+    } else if a == b {
+        RhymeKind::Perfect
+    } else if vowels(&a) == vowels(&b) || coda(&a) == coda(&b) {
+        RhymeKind::Near
+    } else {
+        RhymeKind::NotARhyme
+    }
+}
+
+const VOWELS: &str = "aeiouyæɑɒɔəɛɜɪʊʌɝɚɐɘɵɤøœɶɨʉɯ";
+
+fn is_vowel(c: char) -> bool {
+    VOWELS.contains(c)
+}
+
+/// The sounds from the vowel of the last stressed syllable to the end, or
+/// from the first vowel if no stress is marked.
+fn rhyming_part(ipa: &str) -> Vec<char> {
+    let stressed = match ipa.rfind(['ˈ', '\'']) {
+        Some(mark) => &ipa[mark..],
+        None => ipa,
+    };
+    let sounds: Vec<char> = stressed
+        .chars()
+        .filter(|c| !matches!(c, 'ˈ' | '\'' | 'ˌ' | '.' | ',' | ' '))
+        .collect();
+    match sounds.iter().position(|c| is_vowel(*c)) {
+        Some(start) => sounds[start..].to_vec(),
+        None => Vec::new(),
+    }
+}
+
+fn vowels(sounds: &[char]) -> Vec<char> {
+    sounds.iter().cloned().filter(|c| is_vowel(*c)).collect()
+}
+
+fn coda(sounds: &[char]) -> &[char] {
+    match sounds.iter().rposition(|c| is_vowel(*c)) {
+        Some(last) => &sounds[last + 1..],
+        None => sounds,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lookup::fake::FakeLookup;
+    use crate::rhymes::{classify, RhymeFinder, RhymeKind};
+    use crate::try_parse;
+    use crate::RequestType;
+    use crate::Rhymes;
+
+    #[test]
+    fn it_merges_rhyme_groups() {
+        let rhymes = try_parse::<Rhymes>(
+            r#"{"word": "wind", "rhymes": {"verb": ["find", "kind"], "noun": ["pinned", "find"]}}"#,
+        )
+        .unwrap();
+        assert_eq!(rhymes.all(), vec!["pinned", "find", "kind"]);
+        assert_eq!(rhymes.part_of_speech("verb"), ["find", "kind"]);
+        assert!(rhymes.part_of_speech("adjective").is_empty());
+    }
+
+    #[test]
+    fn it_classifies_rhymes() {
+        assert_eq!(classify("ɡreɪp", "ə'ʃeɪp"), RhymeKind::Perfect);
+        assert_eq!(classify("ɡreɪp", "ɡreɪt"), RhymeKind::Near);
+        assert_eq!(classify("ɡreɪp", "stɑp"), RhymeKind::Near);
+        assert_eq!(classify("ɡreɪp", "wɪn"), RhymeKind::NotARhyme);
+        assert_eq!(classify("ɡreɪp", ""), RhymeKind::Unknown);
+        assert_eq!(classify("'ɔrɪndʒ", "'dɔrɪndʒ"), RhymeKind::Perfect);
+    }
+
+    fn lookup() -> FakeLookup {
+        FakeLookup::new()
+            .reply(
+                "grape",
+                RequestType::Rhymes,
+                r#"{"word": "grape", "rhymes": {"all": ["ape", "escape", "tape", "great"]}}"#,
+            )
+            .word(r#"{"word": "grape", "pronunciation": {"all": "ɡreɪp"}, "results": []}"#)
+            .word(
+                r#"{"word": "ape", "frequency": 3.9, "pronunciation": {"all": "eɪp"},
+                    "syllables": {"count": 1, "list": ["ape"]}, "results": []}"#,
+            )
+            .word(
+                r#"{"word": "escape", "frequency": 4.2, "pronunciation": {"all": "ɪ'skeɪp"},
+                    "syllables": {"count": 2, "list": ["es", "cape"]}, "results": []}"#,
+            )
+            .word(
+                r#"{"word": "great", "frequency": 5.6, "pronunciation": {"all": "ɡreɪt"},
+                    "syllables": {"count": 1, "list": ["great"]}, "results": []}"#,
+            )
+    }
+
+    #[test]
+    fn it_ranks_rhymes_by_frequency() {
+        let lookup = lookup();
+        let rhymes = RhymeFinder::new(&lookup).find("grape").unwrap();
+        let words: Vec<&str> = rhymes.iter().map(|r| r.word.as_str()).collect();
+        assert_eq!(words, vec!["great", "escape", "ape", "tape"]);
+        let kinds: Vec<RhymeKind> = rhymes.iter().map(|r| r.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                RhymeKind::Near,
+                RhymeKind::Perfect,
+                RhymeKind::Perfect,
+                RhymeKind::Unknown
+            ]
+        );
+    }
+
+    #[test]
+    fn it_filters_by_syllables_within_limit() {
+        let lookup = lookup();
+        let rhymes = RhymeFinder::new(&lookup)
+            .limit(3)
+            .syllables(1)
+            .find("grape")
+            .unwrap();
+        let words: Vec<&str> = rhymes.iter().map(|r| r.word.as_str()).collect();
+        assert_eq!(words, vec!["ape"]);
+        assert_eq!(lookup.fetches.get(), 5);
+    }
+}
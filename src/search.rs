@@ -0,0 +1,193 @@
+use serde_json::{Map, Value};
+
+use crate::normalize::percent_encode;
+
+/// Parameters for the API's word search.
+///
+/// ```
+/// use wordsapi::SearchQuery;
+///
+/// let query = SearchQuery::new()
+///     .letter_pattern("^a.{4}$")
+///     .part_of_speech("noun")
+///     .limit(20);
+/// assert_eq!(
+///     query.query_string(),
+///     "letterPattern=%5Ea.%7B4%7D%24&partOfSpeech=noun&limit=20"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    pub letter_pattern: Option<String>,
+    pub letters: Option<usize>,
+    pub letters_min: Option<usize>,
+    pub letters_max: Option<usize>,
+    pub pronunciation_pattern: Option<String>,
+    pub sounds: Option<usize>,
+    pub sounds_min: Option<usize>,
+    pub sounds_max: Option<usize>,
+    pub part_of_speech: Option<String>,
+    pub frequency_min: Option<f32>,
+    pub frequency_max: Option<f32>,
+    /// Only match words that have this detail, such as `definitions`.
+    pub has_details: Option<String>,
+    pub limit: Option<usize>,
+    pub page: Option<usize>,
+}
+
+impl SearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A regular expression the whole word must match.
+    pub fn letter_pattern(mut self, pattern: &str) -> Self {
+        self.letter_pattern = Some(pattern.to_owned());
+        self
+    }
+
+    pub fn letters(mut self, letters: usize) -> Self {
+        self.letters = Some(letters);
+        self
+    }
+
+    pub fn letters_min(mut self, letters: usize) -> Self {
+        self.letters_min = Some(letters);
+        self
+    }
+
+    pub fn letters_max(mut self, letters: usize) -> Self {
+        self.letters_max = Some(letters);
+        self
+    }
+
+    pub fn pronunciation_pattern(mut self, pattern: &str) -> Self {
+        self.pronunciation_pattern = Some(pattern.to_owned());
+        self
+    }
+
+    pub fn sounds(mut self, sounds: usize) -> Self {
+        self.sounds = Some(sounds);
+        self
+    }
+
+    pub fn sounds_min(mut self, sounds: usize) -> Self {
+        self.sounds_min = Some(sounds);
+        self
+    }
+
+    pub fn sounds_max(mut self, sounds: usize) -> Self {
+        self.sounds_max = Some(sounds);
+        self
+    }
+
+    pub fn part_of_speech(mut self, part_of_speech: &str) -> Self {
+        self.part_of_speech = Some(part_of_speech.to_owned());
+        self
+    }
+
+    pub fn frequency_min(mut self, zipf: f32) -> Self {
+        self.frequency_min = Some(zipf);
+        self
+    }
+
+    pub fn frequency_max(mut self, zipf: f32) -> Self {
+        self.frequency_max = Some(zipf);
+        self
+    }
+
+    pub fn has_details(mut self, detail: &str) -> Self {
+        self.has_details = Some(detail.to_owned());
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    pub fn query_string(&self) -> String {
+        let mut params: Vec<(&str, String)> = Vec::new();
+        let mut push = |name, value: Option<String>| {
+            if let Some(value) = value {
+                params.push((name, value));
+            }
+        };
+        push("letterPattern", self.letter_pattern.clone());
+        push("letters", self.letters.map(|v| v.to_string()));
+        push("lettersMin", self.letters_min.map(|v| v.to_string()));
+        push("lettersMax", self.letters_max.map(|v| v.to_string()));
+        push("pronunciationPattern", self.pronunciation_pattern.clone());
+        push("sounds", self.sounds.map(|v| v.to_string()));
+        push("soundsMin", self.sounds_min.map(|v| v.to_string()));
+        push("soundsMax", self.sounds_max.map(|v| v.to_string()));
+        push("partOfSpeech", self.part_of_speech.clone());
+        push("frequencyMin", self.frequency_min.map(|v| v.to_string()));
+        push("frequencyMax", self.frequency_max.map(|v| v.to_string()));
+        push("hasDetails", self.has_details.clone());
+        push("limit", self.limit.map(|v| v.to_string()));
+        push("page", self.page.map(|v| v.to_string()));
+        params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, percent_encode(value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchResults {
+    /// The query as the API understood it.
+    #[serde(default)]
+    pub query: Map<String, Value>,
+    pub results: SearchMatches,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SearchMatches {
+    /// Matches across all pages.
+    pub total: usize,
+    /// Matches on this page.
+    pub data: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::try_parse;
+    use crate::SearchQuery;
+    use crate::SearchResults;
+
+    #[test]
+    fn it_makes_empty_query_string() {
+        assert_eq!(SearchQuery::new().query_string(), "");
+    }
+
+    #[test]
+    fn it_makes_query_string() {
+        let query = SearchQuery::new()
+            .letters(5)
+            .frequency_min(3.5)
+            .has_details("definitions")
+            .page(2);
+        assert_eq!(
+            query.query_string(),
+            "letters=5&frequencyMin=3.5&hasDetails=definitions&page=2"
+        );
+    }
+
+    #[test]
+    fn it_parses_search_results() {
+        let json = r#"{
+            "query": {"letterPattern": "^a.{4}$", "limit": 2, "page": 1},
+            "results": {"total": 1016, "data": ["aalii", "aaron"]}
+        }"#;
+        let results = try_parse::<SearchResults>(json).unwrap();
+        assert_eq!(results.results.total, 1016);
+        assert_eq!(results.results.data, vec!["aalii", "aaron"]);
+    }
+}
@@ -0,0 +1,85 @@
+use core::fmt;
+use hyper::HeaderMap;
+use zeroize::Zeroize;
+
+static REDACTED: &str = "***";
+
+/// A value, such as an API key, that must not end up in logs.
+///
+/// `Debug` and `Display` print `***`, and the value is wiped from memory when
+/// it is dropped. Use `expose` where the value itself is needed.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: &str) -> Self {
+        Secret(value.to_owned())
+    }
+
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Formats `headers` for logging, hiding the values of sensitive headers.
+pub(crate) fn redact_headers(headers: &HeaderMap) -> String {
+    let fields: Vec<String> = headers
+        .iter()
+        .map(|(name, value)| {
+            if value.is_sensitive() {
+                format!("{}: {}", name, REDACTED)
+            } else {
+                format!("{}: {}", name, value.to_str().unwrap_or("<binary>"))
+            }
+        })
+        .collect();
+    format!("{{{}}}", fields.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::secret::redact_headers;
+    use crate::Secret;
+    use hyper::header::HeaderValue;
+    use hyper::HeaderMap;
+
+    #[test]
+    fn it_hides_the_value() {
+        let secret = Secret::new("TEST_TOKEN");
+        assert_eq!(format!("{:?}", secret), "***");
+        assert_eq!(format!("{}", secret), "***");
+        assert_eq!(secret.expose(), "TEST_TOKEN");
+    }
+
+    #[test]
+    fn it_redacts_sensitive_headers() {
+        let mut key = HeaderValue::from_static("TEST_TOKEN");
+        key.set_sensitive(true);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mashape-key", key);
+        headers.insert("x-mashape-host", HeaderValue::from_static("example.com"));
+        let logged = redact_headers(&headers);
+        assert!(!logged.contains("TEST_TOKEN"));
+        assert!(logged.contains("x-mashape-key: ***"));
+        assert!(logged.contains("x-mashape-host: example.com"));
+    }
+}
@@ -0,0 +1,249 @@
+use std::cmp::Ordering;
+
+use crate::frequency::Frequencies;
+use crate::Lookup;
+use crate::RequestError;
+use crate::SearchQuery;
+
+/// Candidates further than this from the misspelling are dropped.
+const MAX_DISTANCE: usize = 2;
+
+/// Longer words are not searched for by their single edits, since that
+/// pattern grows with the square of the word's length.
+const MAX_EDIT_PATTERN_LETTERS: usize = 15;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub word: String,
+    /// Damerau-Levenshtein distance from the misspelling.
+    pub distance: usize,
+    pub zipf: Option<f32>,
+}
+
+/// Up to `limit` known words close to `misspelled`, best first.
+///
+/// Candidates are the words one edit away (a letter changed, added, removed,
+/// or two neighbours swapped) and the words of a similar length with the
+/// same first and last letters. They are ranked by Damerau-Levenshtein
+/// distance, then by how common they are.
+///
+/// This makes two searches plus a frequency lookup for each of the closest
+/// candidates. Giving the client a `Cache` saves repeating them.
+///
+/// ```no_run
+/// use wordsapi::{Client, RequestError, Word};
+///
+/// let client = Client::new("TOKEN");
+/// if let Err(RequestError::NotFound { word }) = client.look_up::<Word>("recieve") {
+///     for suggestion in wordsapi::spell::suggest(&client, &word, 3).unwrap() {
+///         println!("did you mean {}?", suggestion.word);
+///     }
+/// }
+/// ```
+pub fn suggest<L: Lookup>(
+    lookup: &L,
+    misspelled: &str,
+    limit: usize,
+) -> Result<Vec<Suggestion>, RequestError> {
+    let misspelled = misspelled.trim().to_lowercase();
+    if misspelled.is_empty() {
+        return Err(RequestError::InvalidWord {
+            word: misspelled,
+            reason: "word is empty",
+        });
+    }
+    let mut candidates: Vec<(String, usize)> = Vec::new();
+    for query in queries(&misspelled) {
+        for word in lookup.search_parsed(&query)?.results.data {
+            let distance = damerau_levenshtein(&misspelled, &word.to_lowercase());
+            if distance <= MAX_DISTANCE
+                && word != misspelled
+                && !candidates.iter().any(|(known, _)| *known == word)
+            {
+                candidates.push((word, distance));
+            }
+        }
+    }
+    candidates.sort_by_key(|&(_, distance)| distance);
+    // Only the closest candidates are worth a frequency lookup each.
+    candidates.truncate(limit.saturating_mul(3));
+
+    let frequencies = Frequencies::new(lookup);
+    let mut suggestions = Vec::new();
+    for (word, distance) in candidates {
+        let zipf = frequencies.details(&word)?.map(|details| details.zipf);
+        suggestions.push(Suggestion {
+            word,
+            distance,
+            zipf,
+        });
+    }
+    suggestions.sort_by(|a, b| {
+        a.distance.cmp(&b.distance).then_with(|| {
+            let a = a.zipf.unwrap_or(f32::NEG_INFINITY);
+            let b = b.zipf.unwrap_or(f32::NEG_INFINITY);
+            b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+        })
+    });
+    suggestions.truncate(limit);
+    Ok(suggestions)
+}
+
+/// The searches for candidates of `word`, which must be lowercase.
+fn queries(word: &str) -> Vec<SearchQuery> {
+    let letters: Vec<String> = word.chars().map(escape).collect();
+    let length = letters.len();
+    let mut queries = Vec::new();
+    if length <= MAX_EDIT_PATTERN_LETTERS {
+        queries.push(
+            SearchQuery::new()
+                .letter_pattern(&format!("^({})$", single_edits(&letters).join("|")))
+                .letters_min(length.saturating_sub(1).max(1))
+                .letters_max(length + 1)
+                .limit(100),
+        );
+    }
+    if length > 2 {
+        queries.push(
+            SearchQuery::new()
+                .letter_pattern(&format!("^{}.*{}$", letters[0], letters[length - 1]))
+                .letters_min(length.saturating_sub(MAX_DISTANCE).max(2))
+                .letters_max(length + MAX_DISTANCE)
+                .limit(100),
+        );
+    }
+    queries
+}
+
+/// A pattern for every word one edit from `letters`, each letter already
+/// escaped for a regular expression.
+fn single_edits(letters: &[String]) -> Vec<String> {
+    let join = |parts: &[String]| parts.concat();
+    let mut edits: Vec<String> = Vec::new();
+    let mut push = |edit: String| {
+        if !edit.is_empty() && !edits.contains(&edit) {
+            edits.push(edit);
+        }
+    };
+    for i in 0..letters.len() {
+        push(format!(
+            "{}.{}",
+            join(&letters[..i]),
+            join(&letters[i + 1..])
+        ));
+    }
+    for i in 0..=letters.len() {
+        push(format!("{}.{}", join(&letters[..i]), join(&letters[i..])));
+    }
+    for i in 0..letters.len() {
+        push(format!(
+            "{}{}",
+            join(&letters[..i]),
+            join(&letters[i + 1..])
+        ));
+    }
+    for i in 1..letters.len() {
+        let mut swapped = letters.to_vec();
+        swapped.swap(i - 1, i);
+        push(join(&swapped));
+    }
+    edits
+}
+
+fn escape(c: char) -> String {
+    if c.is_alphanumeric() || c == ' ' {
+        c.to_string()
+    } else {
+        format!("\\{}", c)
+    }
+}
+
+/// Edits to turn `a` into `b`, counting a swap of neighbouring letters as one
+/// edit (the optimal string alignment variant).
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut d = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    d[0] = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lookup::fake::FakeLookup;
+    use crate::spell::{damerau_levenshtein, queries, suggest};
+    use crate::RequestType;
+
+    #[test]
+    fn it_measures_edit_distance() {
+        assert_eq!(damerau_levenshtein("teh", "the"), 1);
+        assert_eq!(damerau_levenshtein("recieve", "receive"), 1);
+        assert_eq!(damerau_levenshtein("kitten", "sitting"), 3);
+        assert_eq!(damerau_levenshtein("", "abc"), 3);
+        assert_eq!(damerau_levenshtein("ca", "abc"), 3);
+    }
+
+    #[test]
+    fn it_makes_candidate_patterns() {
+        let queries = queries("teh");
+        assert_eq!(
+            queries[0].letter_pattern.as_deref(),
+            Some("^(.eh|t.h|te.|.teh|t.eh|te.h|teh.|eh|th|te|eth|the)$")
+        );
+        assert_eq!(queries[1].letter_pattern.as_deref(), Some("^t.*h$"));
+        assert_eq!(queries[1].letters_min, Some(2));
+        assert_eq!(queries[1].letters_max, Some(5));
+    }
+
+    #[test]
+    fn it_skips_the_edit_pattern_for_long_words() {
+        let queries = queries("antidisestablishmentarianism");
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].letter_pattern.as_deref(), Some("^a.*m$"));
+    }
+
+    #[test]
+    fn it_ranks_by_distance_then_frequency() {
+        let queries = queries("teh");
+        let frequency = |word: &str, zipf: f32| {
+            format!(
+                r#"{{"word": "{}", "frequency": {{"zipf": {}, "perMillion": 1.0, "diversity": 0.1}}}}"#,
+                word, zipf
+            )
+        };
+        let lookup = FakeLookup::new()
+            .search_reply(
+                &queries[0],
+                r#"{"results": {"total": 4, "data": ["tea", "ten", "the", "eh"]}}"#,
+            )
+            .search_reply(
+                &queries[1],
+                r#"{"results": {"total": 3, "data": ["the", "teach", "thrash"]}}"#,
+            )
+            .reply("the", RequestType::Frequency, &frequency("the", 7.7))
+            .reply("tea", RequestType::Frequency, &frequency("tea", 4.6))
+            .reply("ten", RequestType::Frequency, &frequency("ten", 5.2))
+            .reply("teach", RequestType::Frequency, &frequency("teach", 4.4));
+        let suggestions = suggest(&lookup, "Teh", 4).unwrap();
+        let words: Vec<(&str, usize)> = suggestions
+            .iter()
+            .map(|s| (s.word.as_str(), s.distance))
+            .collect();
+        assert_eq!(words, vec![("the", 1), ("ten", 1), ("tea", 1), ("eh", 1)]);
+        assert_eq!(suggestions[3].zipf, None);
+    }
+}
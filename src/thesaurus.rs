@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::frequency::Frequencies;
+use crate::HasRequestType;
+use crate::Lookup;
+use crate::RequestError;
+use crate::RequestType;
+use crate::Word;
+
+/// The reply to a `RequestType::Synonyms` request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Synonyms {
+    pub word: String,
+    pub synonyms: Vec<String>,
+}
+
+impl HasRequestType for Synonyms {
+    fn request_type() -> RequestType {
+        RequestType::Synonyms
+    }
+}
+
+/// The reply to a `RequestType::Antonyms` request.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Antonyms {
+    pub word: String,
+    pub antonyms: Vec<String>,
+}
+
+impl HasRequestType for Antonyms {
+    fn request_type() -> RequestType {
+        RequestType::Antonyms
+    }
+}
+
+/// The synonyms and antonyms of a word, by sense.
+///
+/// Senses are grouped by part of speech, in the order the parts of speech
+/// first appear in the word's entries. Displaying a `Thesaurus` gives plain
+/// text; serializing it gives JSON.
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct Thesaurus {
+    pub word: String,
+    pub senses: Vec<ThesaurusSense>,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ThesaurusSense {
+    /// The position of the sense in `Word::entries`.
+    pub index: usize,
+    #[serde(rename = "partOfSpeech")]
+    pub part_of_speech: Option<String>,
+    pub definition: String,
+    pub synonyms: Vec<String>,
+    pub antonyms: Vec<String>,
+}
+
+impl Thesaurus {
+    /// Collects the senses of `word` that have synonyms or antonyms. Terms
+    /// are listed once per sense, and never the word itself.
+    pub fn from_word(word: &Word) -> Thesaurus {
+        let mut senses: Vec<ThesaurusSense> = word
+            .entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| ThesaurusSense {
+                index,
+                part_of_speech: entry.part_of_speech.clone(),
+                definition: entry.definition.clone(),
+                synonyms: distinct(&word.word, entry.synonyms.as_deref()),
+                antonyms: distinct(&word.word, entry.antonyms.as_deref()),
+            })
+            .filter(|sense| !sense.synonyms.is_empty() || !sense.antonyms.is_empty())
+            .collect();
+        let mut parts_of_speech: Vec<Option<&str>> = Vec::new();
+        for entry in &word.entries {
+            let part_of_speech = entry.part_of_speech.as_deref();
+            if !parts_of_speech.contains(&part_of_speech) {
+                parts_of_speech.push(part_of_speech);
+            }
+        }
+        senses.sort_by_key(|sense| {
+            parts_of_speech
+                .iter()
+                .position(|p| *p == sense.part_of_speech.as_deref())
+        });
+        Thesaurus {
+            word: word.word.clone(),
+            senses,
+        }
+    }
+
+    /// Every synonym once, across senses, in order.
+    pub fn synonyms(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.senses
+            .iter()
+            .flat_map(|sense| &sense.synonyms)
+            .map(String::as_str)
+            .filter(|term| seen.insert(*term))
+            .collect()
+    }
+
+    /// Every antonym once, across senses, in order.
+    pub fn antonyms(&self) -> Vec<&str> {
+        let mut seen = HashSet::new();
+        self.senses
+            .iter()
+            .flat_map(|sense| &sense.antonyms)
+            .map(String::as_str)
+            .filter(|term| seen.insert(*term))
+            .collect()
+    }
+
+    /// Sorts the terms of each sense most frequent first, so the likeliest
+    /// replacements come first. Terms the API has no frequency for go last.
+    pub fn rank_by_frequency<L: Lookup>(
+        &mut self,
+        frequencies: &Frequencies<L>,
+    ) -> Result<(), RequestError> {
+        for sense in &mut self.senses {
+            frequencies.sort(&mut sense.synonyms)?;
+            frequencies.sort(&mut sense.antonyms)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a Word> for Thesaurus {
+    fn from(word: &'a Word) -> Thesaurus {
+        Thesaurus::from_word(word)
+    }
+}
+
+impl fmt::Display for Thesaurus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.word)?;
+        let mut part_of_speech = None;
+        for (n, sense) in self.senses.iter().enumerate() {
+            if n == 0 || sense.part_of_speech != part_of_speech {
+                part_of_speech = sense.part_of_speech.clone();
+                writeln!(f, "  {}", part_of_speech.as_deref().unwrap_or("other"))?;
+            }
+            writeln!(f, "    {}. {}", n + 1, sense.definition)?;
+            if !sense.synonyms.is_empty() {
+                writeln!(f, "       synonyms: {}", sense.synonyms.join(", "))?;
+            }
+            if !sense.antonyms.is_empty() {
+                writeln!(f, "       antonyms: {}", sense.antonyms.join(", "))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn distinct(word: &str, terms: Option<&[String]>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    terms
+        .unwrap_or(&[])
+        .iter()
+        .filter(|term| term.as_str() != word && seen.insert(term.as_str()))
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::frequency::Frequencies;
+    use crate::lookup::fake::FakeLookup;
+    use crate::thesaurus::{Synonyms, Thesaurus};
+    use crate::try_parse;
+    use crate::RequestType;
+    use crate::Word;
+
+    fn word() -> Word {
+        try_parse::<Word>(
+            r#"{"word": "fast", "results": [
+                {"definition": "acting or moving quickly", "partOfSpeech": "adjective",
+                 "synonyms": ["quick", "rapid", "quick", "fast"], "antonyms": ["slow"]},
+                {"definition": "abstain from eating", "partOfSpeech": "verb"},
+                {"definition": "an abstention from food", "partOfSpeech": "noun", "synonyms": ["fasting"]},
+                {"definition": "securely fixed", "partOfSpeech": "adjective", "synonyms": ["firm", "quick"]}
+            ]}"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_groups_senses_by_part_of_speech() {
+        let thesaurus = Thesaurus::from_word(&word());
+        let indices: Vec<usize> = thesaurus.senses.iter().map(|s| s.index).collect();
+        assert_eq!(indices, vec![0, 3, 2]);
+        assert_eq!(thesaurus.senses[0].synonyms, vec!["quick", "rapid"]);
+        assert_eq!(
+            thesaurus.synonyms(),
+            vec!["quick", "rapid", "firm", "fasting"]
+        );
+        assert_eq!(thesaurus.antonyms(), vec!["slow"]);
+    }
+
+    #[test]
+    fn it_renders_text_and_json() {
+        let thesaurus = Thesaurus::from_word(&word());
+        assert_eq!(
+            thesaurus.to_string(),
+            "fast\n  adjective\n    1. acting or moving quickly\n       synonyms: quick, rapid\n       \
+             antonyms: slow\n    2. securely fixed\n       synonyms: firm, quick\n  noun\n    \
+             3. an abstention from food\n       synonyms: fasting\n"
+        );
+        let json = serde_json::to_value(&thesaurus).unwrap();
+        assert_eq!(json["senses"][1]["partOfSpeech"], "adjective");
+        assert_eq!(json["senses"][2]["synonyms"][0], "fasting");
+    }
+
+    #[test]
+    fn it_ranks_terms_by_frequency() {
+        let frequency = |word: &str, zipf: f32| {
+            format!(
+                r#"{{"word": "{}", "frequency": {{"zipf": {}, "perMillion": 1.0, "diversity": 0.1}}}}"#,
+                word, zipf
+            )
+        };
+        let lookup = FakeLookup::new()
+            .reply("quick", RequestType::Frequency, &frequency("quick", 4.9))
+            .reply("rapid", RequestType::Frequency, &frequency("rapid", 3.8))
+            .reply("firm", RequestType::Frequency, &frequency("firm", 5.1));
+        let mut thesaurus = Thesaurus::from_word(&word());
+        thesaurus
+            .rank_by_frequency(&Frequencies::new(&lookup))
+            .unwrap();
+        assert_eq!(thesaurus.senses[0].synonyms, vec!["quick", "rapid"]);
+        assert_eq!(thesaurus.senses[1].synonyms, vec!["firm", "quick"]);
+    }
+
+    #[test]
+    fn it_parses_synonyms_endpoint() {
+        let reply =
+            try_parse::<Synonyms>(r#"{"word": "lovely", "synonyms": ["adorable", "endearing"]}"#)
+                .unwrap();
+        assert_eq!(reply.synonyms.len(), 2);
+    }
+}
@@ -1,5 +1,7 @@
 use crate::HasRequestType;
 use crate::RequestType;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -7,8 +9,18 @@ pub struct Word {
     pub word: String,
     pub frequency: Option<f32>,
     pub pronunciation: Option<HashMap<String, String>>,
+    pub syllables: Option<Syllables>,
     #[serde(rename = "results")]
     pub entries: Vec<Entry>,
+    /// Fields the API returned that this crate does not model.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Syllables {
+    pub count: u32,
+    pub list: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -38,6 +50,26 @@ pub struct Entry {
     pub similar_to: Option<Vec<String>>,
     #[serde(rename = "pertainsTo")]
     pub pertains_to: Option<Vec<String>>,
+    /// Fields the API returned that this crate does not model.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Word {
+    /// The pronunciation for a part of speech, falling back to the one for
+    /// `all` and then to any other.
+    pub fn pronunciation_of(&self, part_of_speech: Option<&str>) -> Option<&str> {
+        let pronunciation = self.pronunciation.as_ref()?;
+        part_of_speech
+            .and_then(|part_of_speech| pronunciation.get(part_of_speech))
+            .or_else(|| pronunciation.get("all"))
+            .or_else(|| {
+                let mut keys: Vec<&String> = pronunciation.keys().collect();
+                keys.sort();
+                keys.first().map(|key| &pronunciation[*key])
+            })
+            .map(String::as_str)
+    }
 }
 
 impl HasRequestType for Word {
@@ -45,3 +77,219 @@ impl HasRequestType for Word {
         RequestType::Word
     }
 }
+
+/// A `Word` that borrows its strings from the JSON it was parsed from.
+///
+/// Strings without escape sequences are not copied, which makes this much
+/// cheaper than `Word` for large payloads. Fields not modelled here are
+/// skipped rather than collected.
+#[derive(Deserialize, Debug)]
+pub struct WordRef<'a> {
+    #[serde(borrow)]
+    pub word: Cow<'a, str>,
+    pub frequency: Option<f32>,
+    #[serde(borrow, default, deserialize_with = "borrowed::map")]
+    pub pronunciation: Option<HashMap<Cow<'a, str>, Cow<'a, str>>>,
+    #[serde(borrow, default)]
+    pub syllables: Option<SyllablesRef<'a>>,
+    #[serde(borrow, rename = "results")]
+    pub entries: Vec<EntryRef<'a>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct SyllablesRef<'a> {
+    pub count: u32,
+    #[serde(borrow, deserialize_with = "borrowed::items")]
+    pub list: Vec<Cow<'a, str>>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct EntryRef<'a> {
+    #[serde(borrow)]
+    pub definition: Cow<'a, str>,
+    #[serde(
+        borrow,
+        rename = "partOfSpeech",
+        default,
+        deserialize_with = "borrowed::string"
+    )]
+    pub part_of_speech: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrowed::list")]
+    pub derivation: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "hasSubstances",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub has_substances: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "typeOf",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub type_of: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "verbGroup",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub verb_group: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "hasTypes",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub has_types: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "hasParts",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub has_parts: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "memberOf",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub member_of: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "partOf",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub part_of: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow, default, deserialize_with = "borrowed::list")]
+    pub synonyms: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow, default, deserialize_with = "borrowed::list")]
+    pub antonyms: Option<Vec<Cow<'a, str>>>,
+    #[serde(borrow, default, deserialize_with = "borrowed::list")]
+    pub examples: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "similarTo",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub similar_to: Option<Vec<Cow<'a, str>>>,
+    #[serde(
+        borrow,
+        rename = "pertainsTo",
+        default,
+        deserialize_with = "borrowed::list"
+    )]
+    pub pertains_to: Option<Vec<Cow<'a, str>>>,
+}
+
+impl<'a> WordRef<'a> {
+    pub fn to_owned(&self) -> Word {
+        Word {
+            word: self.word.to_string(),
+            frequency: self.frequency,
+            pronunciation: self.pronunciation.as_ref().map(|pronunciation| {
+                pronunciation
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect()
+            }),
+            syllables: self.syllables.as_ref().map(SyllablesRef::to_owned),
+            entries: self.entries.iter().map(EntryRef::to_owned).collect(),
+            extra: Map::new(),
+        }
+    }
+}
+
+impl<'a> SyllablesRef<'a> {
+    pub fn to_owned(&self) -> Syllables {
+        Syllables {
+            count: self.count,
+            list: self.list.iter().map(|item| item.to_string()).collect(),
+        }
+    }
+}
+
+impl<'a> EntryRef<'a> {
+    pub fn to_owned(&self) -> Entry {
+        Entry {
+            definition: self.definition.to_string(),
+            part_of_speech: self.part_of_speech.as_ref().map(|p| p.to_string()),
+            derivation: owned_list(&self.derivation),
+            has_substances: owned_list(&self.has_substances),
+            type_of: owned_list(&self.type_of),
+            verb_group: owned_list(&self.verb_group),
+            has_types: owned_list(&self.has_types),
+            has_parts: owned_list(&self.has_parts),
+            member_of: owned_list(&self.member_of),
+            part_of: owned_list(&self.part_of),
+            synonyms: owned_list(&self.synonyms),
+            antonyms: owned_list(&self.antonyms),
+            examples: owned_list(&self.examples),
+            similar_to: owned_list(&self.similar_to),
+            pertains_to: owned_list(&self.pertains_to),
+            extra: Map::new(),
+        }
+    }
+}
+
+/// Serde only borrows a `Cow<str>` when it is the field type itself, so
+/// optional and nested strings go through these wrappers instead.
+mod borrowed {
+    use serde::{Deserialize, Deserializer};
+    use std::borrow::Cow;
+    use std::collections::HashMap;
+
+    #[derive(Deserialize, PartialEq, Eq, Hash)]
+    struct Str<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    pub fn string<'de, D>(deserializer: D) -> Result<Option<Cow<'de, str>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Option::<Str<'de>>::deserialize(deserializer)?;
+        Ok(value.map(|s| s.0))
+    }
+
+    pub fn items<'de, D>(deserializer: D) -> Result<Vec<Cow<'de, str>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Vec::<Str<'de>>::deserialize(deserializer)?;
+        Ok(value.into_iter().map(|s| s.0).collect())
+    }
+
+    pub fn list<'de, D>(deserializer: D) -> Result<Option<Vec<Cow<'de, str>>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Option::<Vec<Str<'de>>>::deserialize(deserializer)?;
+        Ok(value.map(|items| items.into_iter().map(|s| s.0).collect()))
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn map<'de, D>(
+        deserializer: D,
+    ) -> Result<Option<HashMap<Cow<'de, str>, Cow<'de, str>>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Option::<HashMap<Str<'de>, Str<'de>>>::deserialize(deserializer)?;
+        Ok(value.map(|map| map.into_iter().map(|(k, v)| (k.0, v.0)).collect()))
+    }
+}
+
+fn owned_list(list: &Option<Vec<Cow<str>>>) -> Option<Vec<String>> {
+    list.as_ref()
+        .map(|items| items.iter().map(|item| item.to_string()).collect())
+}
+
+impl<'a> HasRequestType for WordRef<'a> {
+    fn request_type() -> RequestType {
+        RequestType::Word
+    }
+}